@@ -26,6 +26,7 @@ import {
 import BN from 'bn.js';
 import {
   LAUNCHR_PROGRAM_ID,
+  TOKEN_METADATA_PROGRAM_ID,
   SEEDS,
   CreateLaunchParams,
   BuyParams,
@@ -132,6 +133,16 @@ export class LaunchrPDAs {
       this.programId
     );
   }
+
+  /**
+   * Derive Metaplex metadata PDA (owned by the Token Metadata program)
+   */
+  metadata(mint: PublicKey): [PublicKey, number] {
+    return PublicKey.findProgramAddressSync(
+      [SEEDS.METADATA, TOKEN_METADATA_PROGRAM_ID.toBuffer(), mint.toBuffer()],
+      TOKEN_METADATA_PROGRAM_ID
+    );
+  }
 }
 
 // =============================================================================
@@ -162,6 +173,7 @@ function serializeCreateLaunchParams(params: CreateLaunchParams): Buffer {
   // Calculate total size
   // String format: 4 bytes length + content
   // Option<String> format: 1 byte (0/1) + if 1: 4 bytes length + content
+  // Trailing fields are sized for Some; the buffer is sliced to what was written
   const size = 8 + // discriminator
     4 + nameBytes.length +
     4 + symbolBytes.length +
@@ -169,7 +181,14 @@ function serializeCreateLaunchParams(params: CreateLaunchParams): Buffer {
     1 + (params.twitter ? 4 + twitterBytes.length : 0) +
     1 + (params.telegram ? 4 + telegramBytes.length : 0) +
     1 + (params.website ? 4 + websiteBytes.length : 0) +
-    2; // creator_fee_bps (u16)
+    2 + // creator_fee_bps (u16)
+    1 + 8 + // initial_buy_lamports (Option<u64>)
+    8 + // min_tokens_out (u64)
+    1 + 8 + // trading_starts_at (Option<i64>)
+    1 + 4 + // sniper_window_secs (Option<u32>)
+    1 + 2 + // sniper_max_buy_bps (Option<u16>)
+    1 + 2 + // sniper_max_wallet_bps (Option<u16>)
+    1 + 2; // max_wallet_bps (Option<u16>)
 
   const buffer = Buffer.alloc(size);
   let offset = 0;
@@ -237,8 +256,59 @@ function serializeCreateLaunchParams(params: CreateLaunchParams): Buffer {
 
   // Creator fee BPS (u16)
   buffer.writeUInt16LE(params.creatorFeeBps, offset);
+  offset += 2;
+
+  // Initial buy lamports (Option<u64>)
+  if (params.initialBuyLamports) {
+    buffer.writeUInt8(1, offset);
+    offset += 1;
+    params.initialBuyLamports.toArrayLike(Buffer, 'le', 8).copy(buffer, offset);
+    offset += 8;
+  } else {
+    buffer.writeUInt8(0, offset);
+    offset += 1;
+  }
+
+  // Min tokens out (u64)
+  (params.minTokensOut ?? new BN(0)).toArrayLike(Buffer, 'le', 8).copy(buffer, offset);
+  offset += 8;
 
-  return buffer.slice(0, offset + 2);
+  // Trading starts at (Option<i64>)
+  if (params.tradingStartsAt) {
+    buffer.writeUInt8(1, offset);
+    offset += 1;
+    params.tradingStartsAt.toTwos(64).toArrayLike(Buffer, 'le', 8).copy(buffer, offset);
+    offset += 8;
+  } else {
+    buffer.writeUInt8(0, offset);
+    offset += 1;
+  }
+
+  // Sniper window secs (Option<u32>)
+  if (params.sniperWindowSecs != null) {
+    buffer.writeUInt8(1, offset);
+    offset += 1;
+    buffer.writeUInt32LE(params.sniperWindowSecs, offset);
+    offset += 4;
+  } else {
+    buffer.writeUInt8(0, offset);
+    offset += 1;
+  }
+
+  // Sniper max buy, sniper max wallet and max wallet BPS (Option<u16>)
+  for (const bps of [params.sniperMaxBuyBps, params.sniperMaxWalletBps, params.maxWalletBps]) {
+    if (bps != null) {
+      buffer.writeUInt8(1, offset);
+      offset += 1;
+      buffer.writeUInt16LE(bps, offset);
+      offset += 2;
+    } else {
+      buffer.writeUInt8(0, offset);
+      offset += 1;
+    }
+  }
+
+  return buffer.slice(0, offset);
 }
 
 /**
@@ -438,6 +508,19 @@ export class LaunchrClient {
     const [launchAuthority] = this.pdas.launchAuthority(launchPda);
     const [tokenVault] = this.pdas.tokenVault(launchPda);
     const [graduationVault] = this.pdas.graduationVault(launchPda);
+    const [curveVault] = this.pdas.curveVault(launchPda);
+    const [feeVault] = this.pdas.feeVault(configPda);
+    const [metadata] = this.pdas.metadata(mint.publicKey);
+
+    // Creator's position and ATA are only needed for an initial buy;
+    // Anchor reads the program ID in an optional account's slot as None
+    const hasInitialBuy = !!params.initialBuyLamports && !params.initialBuyLamports.isZero();
+    const creatorPosition = hasInitialBuy
+      ? this.pdas.userPosition(launchPda, creator)[0]
+      : this.programId;
+    const creatorAta = hasInitialBuy
+      ? await getAssociatedTokenAddress(mint.publicKey, creator)
+      : this.programId;
 
     // Build instruction
     const instruction = new TransactionInstruction({
@@ -450,7 +533,14 @@ export class LaunchrClient {
         { pubkey: launchAuthority, isSigner: false, isWritable: false },
         { pubkey: tokenVault, isSigner: false, isWritable: true },
         { pubkey: graduationVault, isSigner: false, isWritable: true },
+        { pubkey: curveVault, isSigner: false, isWritable: true },
+        { pubkey: feeVault, isSigner: false, isWritable: true },
+        { pubkey: creatorPosition, isSigner: false, isWritable: hasInitialBuy },
+        { pubkey: creatorAta, isSigner: false, isWritable: hasInitialBuy },
+        { pubkey: metadata, isSigner: false, isWritable: true },
         { pubkey: TOKEN_PROGRAM_ID, isSigner: false, isWritable: false },
+        { pubkey: TOKEN_METADATA_PROGRAM_ID, isSigner: false, isWritable: false },
+        { pubkey: ASSOCIATED_TOKEN_PROGRAM_ID, isSigner: false, isWritable: false },
         { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
         { pubkey: SYSVAR_RENT_PUBKEY, isSigner: false, isWritable: false },
       ],
@@ -586,6 +676,7 @@ export class LaunchrClient {
     const [tokenVault] = this.pdas.tokenVault(launchPk);
     const [graduationVault] = this.pdas.graduationVault(launchPk);
     const [curveVault] = this.pdas.curveVault(launchPk);
+    const [metadata] = this.pdas.metadata(mint);
 
     // Derive Orbit Finance PDAs
     // Pool PDA: [pool, base_mint, quote_mint] from Orbit program
@@ -699,8 +790,12 @@ export class LaunchrClient {
         { pubkey: orbitProtocolFeeVault, isSigner: false, isWritable: true },
         { pubkey: orbitBinArray, isSigner: false, isWritable: true },
         { pubkey: orbitPosition, isSigner: false, isWritable: true },
-        // System programs
+        // Metaplex metadata (locked at graduation if the launch authority holds it)
+        { pubkey: metadata, isSigner: false, isWritable: true },
+        // System programs (launch token and quote mint are both SPL Token for Orbit)
+        { pubkey: TOKEN_PROGRAM_ID, isSigner: false, isWritable: false },
         { pubkey: TOKEN_PROGRAM_ID, isSigner: false, isWritable: false },
+        { pubkey: TOKEN_METADATA_PROGRAM_ID, isSigner: false, isWritable: false },
         { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
         { pubkey: SYSVAR_RENT_PUBKEY, isSigner: false, isWritable: false },
       ],
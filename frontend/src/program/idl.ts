@@ -26,6 +26,11 @@ export const LAUNCHR_PROGRAM_ID = new PublicKey(
   process.env.REACT_APP_PROGRAM_ID || '11111111111111111111111111111111'
 );
 
+// Metaplex Token Metadata program (owns each launch's metadata account)
+export const TOKEN_METADATA_PROGRAM_ID = new PublicKey(
+  'metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s'
+);
+
 // =============================================================================
 // SEEDS
 // =============================================================================
@@ -39,6 +44,7 @@ export const SEEDS = {
   LAUNCH_AUTHORITY: Buffer.from('launch_authority'),
   FEE_VAULT: Buffer.from('fee_vault'),
   GRADUATION_VAULT: Buffer.from('graduation_vault'),
+  METADATA: Buffer.from('metadata'),
 } as const;
 
 // =============================================================================
@@ -134,6 +140,18 @@ export interface CreateLaunchParams {
   telegram: string | null;
   website: string | null;
   creatorFeeBps: number;
+  /** SOL the creator buys with in the same instruction (omit for no initial buy) */
+  initialBuyLamports?: BN | null;
+  /** Minimum tokens the initial buy must return */
+  minTokensOut?: BN;
+  /** Unix timestamp from which trading opens (omit to open immediately) */
+  tradingStartsAt?: BN | null;
+  /** Anti-sniper overrides (omit to use the config's) */
+  sniperWindowSecs?: number | null;
+  sniperMaxBuyBps?: number | null;
+  sniperMaxWalletBps?: number | null;
+  /** Holding cap per wallet while on the curve, BPS of supply (omit for none) */
+  maxWalletBps?: number | null;
 }
 
 export interface BuyParams {
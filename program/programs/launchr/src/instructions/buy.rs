@@ -1,13 +1,19 @@
 //! Launchr - Buy Tokens
 //! 
 //! Buy tokens from the bonding curve using SOL.
+//! The buy that reaches the graduation threshold is filled only up to it;
+//! the rest of the buyer's SOL is left untouched.
+//!
+//! During a launch's anti-sniper window each buy is capped in SOL and each
+//! wallet in tokens bought; both caps relax linearly to unlimited.
+//! A launch's `max_wallet_bps` caps what any wallet may hold until graduation.
 
 use anchor_lang::prelude::*;
 use anchor_lang::system_program;
-use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+use anchor_spl::token_interface::{self, Mint, TokenAccount, TokenInterface, TransferChecked};
 use crate::seeds::*;
 use crate::state::*;
-use crate::math::{bonding_curve, LaunchrError};
+use crate::math::{bonding_curve, LaunchrError, SwapResult, MIN_TRADE_AMOUNT};
 
 /// Buy tokens from the bonding curve
 #[derive(Accounts)]
@@ -30,7 +36,10 @@ pub struct Buy<'info> {
         mut,
         seeds = [LAUNCH_SEED, launch.mint.as_ref()],
         bump = launch.bump,
-        constraint = launch.is_tradeable() @ LaunchrError::LaunchNotActive
+        constraint = launch.status == LaunchStatus::Active @ LaunchrError::LaunchNotActive,
+        constraint = launch.is_tradeable(Clock::get()?.unix_timestamp) @ LaunchrError::TradingNotStarted,
+        constraint = !launch.is_paused() @ LaunchrError::LaunchPaused,
+        constraint = !launch.is_delisted() @ LaunchrError::LaunchDelisted
     )]
     pub launch: Box<Account<'info, Launch>>,
     
@@ -49,7 +58,7 @@ pub struct Buy<'info> {
         bump,
         constraint = token_vault.mint == launch.mint @ LaunchrError::InvalidConfig
     )]
-    pub token_vault: Account<'info, TokenAccount>,
+    pub token_vault: InterfaceAccount<'info, TokenAccount>,
     
     /// SOL curve vault (destination for SOL)
     /// CHECK: PDA for holding SOL
@@ -66,14 +75,16 @@ pub struct Buy<'info> {
         payer = buyer,
         associated_token::mint = mint,
         associated_token::authority = buyer,
+        associated_token::token_program = token_program,
     )]
-    pub buyer_token_account: Account<'info, TokenAccount>,
+    pub buyer_token_account: InterfaceAccount<'info, TokenAccount>,
     
     /// Token mint
     #[account(
+        mint::token_program = token_program,
         constraint = mint.key() == launch.mint @ LaunchrError::InvalidConfig
     )]
-    pub mint: Account<'info, anchor_spl::token::Mint>,
+    pub mint: InterfaceAccount<'info, Mint>,
     
     /// User position (created if first trade)
     #[account(
@@ -102,8 +113,8 @@ pub struct Buy<'info> {
     )]
     pub creator: UncheckedAccount<'info>,
     
-    /// Token program
-    pub token_program: Program<'info, Token>,
+    /// Token program (SPL Token or Token-2022, matching the mint)
+    pub token_program: Interface<'info, TokenInterface>,
     
     /// Associated token program
     pub associated_token_program: Program<'info, anchor_spl::associated_token::AssociatedToken>,
@@ -115,51 +126,267 @@ pub struct Buy<'info> {
 /// Parameters for buying tokens
 #[derive(AnchorSerialize, AnchorDeserialize, Clone)]
 pub struct BuyParams {
-    /// Amount of SOL to spend (in lamports)
+    /// Amount of SOL to spend (in lamports, capped at the graduation threshold)
     pub sol_amount: u64,
-    /// Minimum tokens to receive (slippage protection)
+    /// Minimum tokens to receive for the full `sol_amount` (slippage protection,
+    /// scaled down pro rata when the buy is capped)
     pub min_tokens_out: u64,
 }
 
+/// Parameters for buying tokens with execution guards
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct BuyV2Params {
+    /// Amount of SOL to spend (in lamports, capped at the graduation threshold)
+    pub sol_amount: u64,
+    /// Minimum tokens to receive for the full `sol_amount` (slippage protection,
+    /// scaled down pro rata when the buy is capped)
+    pub min_tokens_out: u64,
+    /// Unix timestamp after which the buy is rejected (optional)
+    pub deadline: Option<i64>,
+    /// Maximum price impact in basis points (optional)
+    pub max_price_impact_bps: Option<u16>,
+}
+
+/// Parameters for buying an exact amount of tokens
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct BuyExactOutParams {
+    /// Exact amount of tokens to receive
+    pub tokens_out: u64,
+    /// Maximum SOL to spend including fees (slippage protection)
+    pub max_sol_in: u64,
+}
+
 /// Buy tokens from the bonding curve
 pub fn buy(ctx: Context<Buy>, params: BuyParams) -> Result<()> {
-    let launch = &mut ctx.accounts.launch;
-    let config = &mut ctx.accounts.config;
-    let user_position = &mut ctx.accounts.user_position;
-    let clock = Clock::get()?;
+    buy_v2(ctx, BuyV2Params {
+        sol_amount: params.sol_amount,
+        min_tokens_out: params.min_tokens_out,
+        deadline: None,
+        max_price_impact_bps: None,
+    })
+}
+
+/// Buy tokens from the bonding curve, guarded by a deadline and a price impact limit
+pub fn buy_v2(ctx: Context<Buy>, params: BuyV2Params) -> Result<()> {
+    check_deadline(params.deadline)?;
+    let launch = &ctx.accounts.launch;
+    let protocol_fee_bps = ctx.accounts.config.protocol_fee_bps;
+    
+    // Cap the input at what fills the curve exactly to the threshold
+    // (never below the minimum trade, so a tiny remainder can still be filled)
+    let fill_to_threshold = bonding_curve::calculate_sol_for_net_input(
+        launch.sol_to_threshold(),
+        protocol_fee_bps,
+        launch.creator_fee_bps,
+    )?
+    .max(MIN_TRADE_AMOUNT);
+    let sol_amount = params.sol_amount.min(fill_to_threshold);
     
     // Calculate swap
     let swap_result = bonding_curve::calculate_buy(
+        sol_amount,
+        launch.virtual_sol_reserve,
+        launch.virtual_token_reserve,
+        protocol_fee_bps,
+        launch.creator_fee_bps,
+    )?;
+    
+    // Check slippage (pro rata to the filled amount)
+    let min_tokens_out = if sol_amount < params.sol_amount {
+        (params.min_tokens_out as u128 * sol_amount as u128 / params.sol_amount as u128) as u64
+    } else {
+        params.min_tokens_out
+    };
+    require!(
+        swap_result.amount_out >= min_tokens_out,
+        LaunchrError::SlippageExceeded
+    );
+    check_price_impact(&swap_result, params.max_price_impact_bps)?;
+    
+    let user_position_bump = ctx.bumps.user_position;
+    settle_buy(
+        BuyAccounts::from_buy(ctx.accounts, user_position_bump),
         params.sol_amount,
+        sol_amount,
+        swap_result.amount_out,
+        &swap_result,
+    )
+}
+
+/// Buy an exact amount of tokens from the bonding curve
+pub fn buy_exact_out(ctx: Context<Buy>, params: BuyExactOutParams) -> Result<()> {
+    let launch = &ctx.accounts.launch;
+    let protocol_fee_bps = ctx.accounts.config.protocol_fee_bps;
+    
+    // SOL needed for the exact output, fees included and rounded up
+    let sol_amount = bonding_curve::calculate_sol_for_tokens(
+        params.tokens_out,
         launch.virtual_sol_reserve,
         launch.virtual_token_reserve,
-        config.protocol_fee_bps,
+        protocol_fee_bps,
         launch.creator_fee_bps,
     )?;
     
     // Check slippage
+    require!(sol_amount <= params.max_sol_in, LaunchrError::SlippageExceeded);
+    
+    // An exact output can't be capped - the final fill must go through `buy`
+    let fill_to_threshold = bonding_curve::calculate_sol_for_net_input(
+        launch.sol_to_threshold(),
+        protocol_fee_bps,
+        launch.creator_fee_bps,
+    )?;
     require!(
-        swap_result.amount_out >= params.min_tokens_out,
-        LaunchrError::SlippageExceeded
+        sol_amount <= fill_to_threshold.max(MIN_TRADE_AMOUNT),
+        LaunchrError::GraduationThresholdExceeded
+    );
+    
+    // Fee legs and post-trade price for the rounded-up input
+    let swap_result = bonding_curve::calculate_buy(
+        sol_amount,
+        launch.virtual_sol_reserve,
+        launch.virtual_token_reserve,
+        protocol_fee_bps,
+        launch.creator_fee_bps,
+    )?;
+    require!(
+        swap_result.amount_out >= params.tokens_out,
+        LaunchrError::InsufficientOutput
     );
     
+    let user_position_bump = ctx.bumps.user_position;
+    settle_buy(
+        BuyAccounts::from_buy(ctx.accounts, user_position_bump),
+        sol_amount,
+        sol_amount,
+        params.tokens_out,
+        &swap_result,
+    )
+}
+
+/// Reject a trade that lands after its deadline
+pub(crate) fn check_deadline(deadline: Option<i64>) -> Result<()> {
+    if let Some(deadline) = deadline {
+        require!(
+            Clock::get()?.unix_timestamp <= deadline,
+            LaunchrError::DeadlineExceeded
+        );
+    }
+    Ok(())
+}
+
+/// Reject a trade that moves the price further than the trader allows
+pub(crate) fn check_price_impact(swap_result: &SwapResult, max_price_impact_bps: Option<u16>) -> Result<()> {
+    if let Some(max_bps) = max_price_impact_bps {
+        require!(
+            swap_result.price_impact_bps <= max_bps as u64,
+            LaunchrError::PriceImpactExceeded
+        );
+    }
+    Ok(())
+}
+
+/// Accounts a priced buy settles between
+///
+/// Built from `Buy` for trades on the curve, and by `create_launch` for the
+/// creator's initial buy.
+pub(crate) struct BuyAccounts<'a, 'info> {
+    pub buyer: AccountInfo<'info>,
+    pub buyer_token_account: AccountInfo<'info>,
+    /// Buyer's on-chain token balance before the buy
+    pub buyer_token_balance: u64,
+    pub creator: AccountInfo<'info>,
+    pub config: &'a mut Account<'info, Config>,
+    pub launch: &'a mut Account<'info, Launch>,
+    pub launch_authority: AccountInfo<'info>,
+    pub user_position: &'a mut Account<'info, UserPosition>,
+    pub user_position_bump: u8,
+    pub mint: AccountInfo<'info>,
+    pub mint_decimals: u8,
+    pub token_vault: AccountInfo<'info>,
+    pub curve_vault: AccountInfo<'info>,
+    pub fee_vault: AccountInfo<'info>,
+    pub token_program: AccountInfo<'info>,
+    pub system_program: AccountInfo<'info>,
+}
+
+impl<'a, 'info> BuyAccounts<'a, 'info> {
+    fn from_buy(accounts: &'a mut Buy<'info>, user_position_bump: u8) -> Self {
+        Self {
+            buyer: accounts.buyer.to_account_info(),
+            buyer_token_account: accounts.buyer_token_account.to_account_info(),
+            buyer_token_balance: accounts.buyer_token_account.amount,
+            creator: accounts.creator.to_account_info(),
+            config: &mut accounts.config,
+            launch: &mut accounts.launch,
+            launch_authority: accounts.launch_authority.to_account_info(),
+            user_position: &mut accounts.user_position,
+            user_position_bump,
+            mint: accounts.mint.to_account_info(),
+            mint_decimals: accounts.mint.decimals,
+            token_vault: accounts.token_vault.to_account_info(),
+            curve_vault: accounts.curve_vault.to_account_info(),
+            fee_vault: accounts.fee_vault.to_account_info(),
+            token_program: accounts.token_program.to_account_info(),
+            system_program: accounts.system_program.to_account_info(),
+        }
+    }
+}
+
+/// Move SOL and tokens for a priced buy and record it
+///
+/// `sol_amount` is the filled input, which may be below `requested_sol_amount`
+/// when capped at the threshold. `token_amount` is what the buyer receives; it
+/// may be below `swap_result.amount_out` when the input was rounded up in the
+/// pool's favour.
+pub(crate) fn settle_buy(
+    accounts: BuyAccounts,
+    requested_sol_amount: u64,
+    sol_amount: u64,
+    token_amount: u64,
+    swap_result: &SwapResult,
+) -> Result<()> {
+    let launch = accounts.launch;
+    let config = accounts.config;
+    let user_position = accounts.user_position;
+    let clock = Clock::get()?;
+    
     // Check sufficient tokens in vault
     require!(
-        swap_result.amount_out <= launch.real_token_reserve,
+        token_amount <= launch.real_token_reserve,
         LaunchrError::InsufficientLiquidity
     );
     
+    // Anti-sniper caps, relaxing over the window after trading starts
+    if let Some(max_sol) = launch.sniper_max_buy_lamports(clock.unix_timestamp) {
+        require!(sol_amount <= max_sol, LaunchrError::SniperBuyCapExceeded);
+    }
+    if let Some(max_tokens) = launch.sniper_max_wallet_tokens(clock.unix_timestamp) {
+        let tokens_bought = user_position.tokens_bought.saturating_add(token_amount);
+        require!(tokens_bought <= max_tokens, LaunchrError::SniperWalletCapExceeded);
+    }
+    
+    // Holding cap, checked against both the tracked and the on-chain balance
+    if let Some(max_tokens) = launch.max_wallet_tokens() {
+        let position_balance = user_position.token_balance.saturating_add(token_amount);
+        let account_balance = accounts.buyer_token_balance.saturating_add(token_amount);
+        require!(
+            position_balance.max(account_balance) <= max_tokens,
+            LaunchrError::WalletCapExceeded
+        );
+    }
+    
     // Transfer SOL to curve vault (minus fees)
-    let sol_to_vault = params.sol_amount
+    let sol_to_vault = sol_amount
         .saturating_sub(swap_result.protocol_fee)
         .saturating_sub(swap_result.creator_fee);
     
     system_program::transfer(
         CpiContext::new(
-            ctx.accounts.system_program.to_account_info(),
+            accounts.system_program.clone(),
             system_program::Transfer {
-                from: ctx.accounts.buyer.to_account_info(),
-                to: ctx.accounts.curve_vault.to_account_info(),
+                from: accounts.buyer.clone(),
+                to: accounts.curve_vault.clone(),
             },
         ),
         sol_to_vault,
@@ -169,24 +396,25 @@ pub fn buy(ctx: Context<Buy>, params: BuyParams) -> Result<()> {
     if swap_result.protocol_fee > 0 {
         system_program::transfer(
             CpiContext::new(
-                ctx.accounts.system_program.to_account_info(),
+                accounts.system_program.clone(),
                 system_program::Transfer {
-                    from: ctx.accounts.buyer.to_account_info(),
-                    to: ctx.accounts.fee_vault.to_account_info(),
+                    from: accounts.buyer.clone(),
+                    to: accounts.fee_vault.clone(),
                 },
             ),
             swap_result.protocol_fee,
         )?;
     }
     
-    // Transfer creator fee
-    if swap_result.creator_fee > 0 {
+    // Transfer creator fee (a creator buying their own launch keeps it)
+    let self_buy = accounts.buyer.key() == accounts.creator.key();
+    if swap_result.creator_fee > 0 && !self_buy {
         system_program::transfer(
             CpiContext::new(
-                ctx.accounts.system_program.to_account_info(),
+                accounts.system_program.clone(),
                 system_program::Transfer {
-                    from: ctx.accounts.buyer.to_account_info(),
-                    to: ctx.accounts.creator.to_account_info(),
+                    from: accounts.buyer.clone(),
+                    to: accounts.creator.clone(),
                 },
             ),
             swap_result.creator_fee,
@@ -202,54 +430,69 @@ pub fn buy(ctx: Context<Buy>, params: BuyParams) -> Result<()> {
     ];
     let signer_seeds = &[authority_seeds];
     
-    token::transfer(
+    token_interface::transfer_checked(
         CpiContext::new_with_signer(
-            ctx.accounts.token_program.to_account_info(),
-            Transfer {
-                from: ctx.accounts.token_vault.to_account_info(),
-                to: ctx.accounts.buyer_token_account.to_account_info(),
-                authority: ctx.accounts.launch_authority.to_account_info(),
+            accounts.token_program.clone(),
+            TransferChecked {
+                from: accounts.token_vault.clone(),
+                mint: accounts.mint.clone(),
+                to: accounts.buyer_token_account.clone(),
+                authority: accounts.launch_authority.clone(),
             },
             signer_seeds,
         ),
-        swap_result.amount_out,
+        token_amount,
+        accounts.mint_decimals,
     )?;
     
     // Update launch state
-    launch.record_buy(swap_result.amount_out, sol_to_vault);
+    launch.record_buy(token_amount, sol_to_vault);
     
     // Update user position
     if user_position.is_new() {
         user_position.init(
-            launch.key(),
-            ctx.accounts.buyer.key(),
-            ctx.bumps.user_position,
+            launch_key,
+            accounts.buyer.key(),
+            accounts.user_position_bump,
             clock.unix_timestamp,
         );
-        launch.holder_count = launch.holder_count.saturating_add(1);
     }
-    user_position.record_buy(swap_result.amount_out, params.sol_amount, clock.unix_timestamp);
+    // Cost basis is what actually left the buyer's wallet
+    let sol_paid = if self_buy {
+        sol_to_vault.saturating_add(swap_result.protocol_fee)
+    } else {
+        sol_amount
+    };
+    user_position.record_buy(token_amount, sol_paid, clock.unix_timestamp);
+    launch.update_holder(user_position);
     
     // Update global stats
-    config.record_trade(params.sol_amount, swap_result.protocol_fee);
+    config.record_trade(sol_amount, swap_result.protocol_fee);
     
     // Emit event
     emit!(TradeExecuted {
-        launch: launch.key(),
-        trader: ctx.accounts.buyer.key(),
+        launch: launch_key,
+        trader: accounts.buyer.key(),
         is_buy: true,
-        sol_amount: params.sol_amount,
-        token_amount: swap_result.amount_out,
+        sol_amount,
+        token_amount,
         price: swap_result.price_after,
         protocol_fee: swap_result.protocol_fee,
         creator_fee: swap_result.creator_fee,
         timestamp: clock.unix_timestamp,
+        requested_sol_amount,
     });
     
     msg!("Buy executed: {} SOL -> {} tokens", 
-        params.sol_amount as f64 / 1e9,
-        swap_result.amount_out as f64 / 1e9
+        sol_amount as f64 / 1e9,
+        token_amount as f64 / 1e9
     );
+    if sol_amount < requested_sol_amount {
+        msg!("Capped at graduation threshold: {} of {} SOL filled",
+            sol_amount as f64 / 1e9,
+            requested_sol_amount as f64 / 1e9
+        );
+    }
     msg!("New price: {} lamports/token", swap_result.price_after);
     msg!("Price impact: {} bps", swap_result.price_impact_bps);
     
@@ -267,10 +510,13 @@ pub struct TradeExecuted {
     pub launch: Pubkey,
     pub trader: Pubkey,
     pub is_buy: bool,
+    /// SOL actually filled
     pub sol_amount: u64,
     pub token_amount: u64,
     pub price: u64,
     pub protocol_fee: u64,
     pub creator_fee: u64,
     pub timestamp: i64,
+    /// SOL the trader asked to spend (buys) or receive (sells)
+    pub requested_sol_amount: u64,
 }
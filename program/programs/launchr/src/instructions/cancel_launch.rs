@@ -0,0 +1,239 @@
+//! Launchr - Cancel Launch
+//!
+//! Let a creator retract a launch before anyone else has traded it.
+//! Burns the curve and graduation supply, refunds the curve SOL and closes
+//! the vaults and launch account so the creator gets the rent back.
+
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use anchor_spl::token_interface::{self, Burn, CloseAccount, Mint, TokenAccount, TokenInterface};
+use crate::seeds::*;
+use crate::state::*;
+use crate::math::LaunchrError;
+
+/// Cancel a launch
+#[derive(Accounts)]
+pub struct CancelLaunch<'info> {
+    /// Creator of the launch
+    #[account(mut)]
+    pub creator: Signer<'info>,
+
+    /// Launch account (closed to creator)
+    #[account(
+        mut,
+        close = creator,
+        seeds = [LAUNCH_SEED, launch.mint.as_ref()],
+        bump = launch.bump,
+        constraint = launch.creator == creator.key() @ LaunchrError::Unauthorized,
+        constraint = launch.status == LaunchStatus::Active @ LaunchrError::LaunchNotActive
+    )]
+    pub launch: Box<Account<'info, Launch>>,
+
+    /// Launch authority PDA
+    /// CHECK: PDA checked by seeds
+    #[account(
+        seeds = [LAUNCH_AUTHORITY_SEED, launch.key().as_ref()],
+        bump = launch.authority_bump
+    )]
+    pub launch_authority: UncheckedAccount<'info>,
+
+    /// Token mint
+    #[account(
+        mut,
+        mint::token_program = token_program,
+        constraint = mint.key() == launch.mint @ LaunchrError::InvalidConfig
+    )]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    /// Token vault (bonding curve tokens)
+    #[account(
+        mut,
+        seeds = [TOKEN_VAULT_SEED, launch.key().as_ref()],
+        bump,
+        constraint = token_vault.mint == launch.mint @ LaunchrError::InvalidConfig
+    )]
+    pub token_vault: InterfaceAccount<'info, TokenAccount>,
+
+    /// LP reserve token vault
+    #[account(
+        mut,
+        seeds = [GRADUATION_VAULT_SEED, launch.key().as_ref()],
+        bump,
+        constraint = graduation_vault.mint == launch.mint @ LaunchrError::InvalidConfig
+    )]
+    pub graduation_vault: InterfaceAccount<'info, TokenAccount>,
+
+    /// SOL curve vault
+    /// CHECK: PDA holding SOL
+    #[account(
+        mut,
+        seeds = [CURVE_VAULT_SEED, launch.key().as_ref()],
+        bump
+    )]
+    pub curve_vault: UncheckedAccount<'info>,
+
+    /// Creator's position (required if the creator has traded)
+    #[account(
+        mut,
+        close = creator,
+        seeds = [USER_POSITION_SEED, launch.key().as_ref(), creator.key().as_ref()],
+        bump = creator_position.bump
+    )]
+    pub creator_position: Option<Account<'info, UserPosition>>,
+
+    /// Creator's token account (required if the creator holds curve tokens)
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = creator,
+        associated_token::token_program = token_program,
+    )]
+    pub creator_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Token program (SPL Token or Token-2022, matching the mint)
+    pub token_program: Interface<'info, TokenInterface>,
+
+    /// System program
+    pub system_program: Program<'info, System>,
+}
+
+/// Cancel a launch and refund the creator
+pub fn cancel_launch(ctx: Context<CancelLaunch>) -> Result<()> {
+    let launch = &mut ctx.accounts.launch;
+    let clock = Clock::get()?;
+
+    // Only the creator may have traded so far
+    let creator_trades = ctx.accounts.creator_position
+        .as_ref()
+        .map(|position| position.total_trades() as u64)
+        .unwrap_or(0);
+    require!(
+        launch.trade_count == creator_trades,
+        LaunchrError::LaunchHasTrades
+    );
+
+    let launch_key = launch.key();
+    let authority_seeds: &[&[u8]] = &[
+        LAUNCH_AUTHORITY_SEED,
+        launch_key.as_ref(),
+        &[launch.authority_bump],
+    ];
+    let signer_seeds = &[authority_seeds];
+
+    // Burn circulating supply - every token outside the curve belongs to the creator
+    let circulating = launch.tokens_sold;
+    if circulating > 0 {
+        let creator_token_account = ctx.accounts.creator_token_account
+            .as_ref()
+            .ok_or(error!(LaunchrError::InsufficientLiquidity))?;
+        require!(
+            creator_token_account.amount >= circulating,
+            LaunchrError::InsufficientLiquidity
+        );
+
+        token_interface::burn(
+            CpiContext::new(
+                ctx.accounts.token_program.to_account_info(),
+                Burn {
+                    mint: ctx.accounts.mint.to_account_info(),
+                    from: creator_token_account.to_account_info(),
+                    authority: ctx.accounts.creator.to_account_info(),
+                },
+            ),
+            circulating,
+        )?;
+    }
+
+    // Burn and close the curve and graduation vaults
+    let vault_tokens = ctx.accounts.token_vault.amount;
+    let graduation_tokens = ctx.accounts.graduation_vault.amount;
+
+    for (vault, amount) in [
+        (&ctx.accounts.token_vault, vault_tokens),
+        (&ctx.accounts.graduation_vault, graduation_tokens),
+    ] {
+        if amount > 0 {
+            token_interface::burn(
+                CpiContext::new_with_signer(
+                    ctx.accounts.token_program.to_account_info(),
+                    Burn {
+                        mint: ctx.accounts.mint.to_account_info(),
+                        from: vault.to_account_info(),
+                        authority: ctx.accounts.launch_authority.to_account_info(),
+                    },
+                    signer_seeds,
+                ),
+                amount,
+            )?;
+        }
+
+        token_interface::close_account(CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            CloseAccount {
+                account: vault.to_account_info(),
+                destination: ctx.accounts.creator.to_account_info(),
+                authority: ctx.accounts.launch_authority.to_account_info(),
+            },
+            signer_seeds,
+        ))?;
+    }
+
+    // Refund everything in the curve vault (System Program owned, so sign as the PDA)
+    let sol_refunded = ctx.accounts.curve_vault.lamports();
+    if sol_refunded > 0 {
+        let curve_vault_bump = ctx.bumps.curve_vault;
+        let curve_vault_seeds: &[&[u8]] = &[
+            CURVE_VAULT_SEED,
+            launch_key.as_ref(),
+            &[curve_vault_bump],
+        ];
+
+        system_program::transfer(
+            CpiContext::new_with_signer(
+                ctx.accounts.system_program.to_account_info(),
+                system_program::Transfer {
+                    from: ctx.accounts.curve_vault.to_account_info(),
+                    to: ctx.accounts.creator.to_account_info(),
+                },
+                &[curve_vault_seeds],
+            ),
+            sol_refunded,
+        )?;
+    }
+
+    launch.status = LaunchStatus::Cancelled;
+
+    let tokens_burned = circulating
+        .saturating_add(vault_tokens)
+        .saturating_add(graduation_tokens);
+
+    emit!(LaunchCancelled {
+        launch: launch_key,
+        mint: launch.mint,
+        creator: launch.creator,
+        sol_refunded,
+        tokens_burned,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Launch cancelled: {} ({})", launch.name_str(), launch.symbol_str());
+    msg!("Burned {} tokens, refunded {} SOL",
+        tokens_burned as f64 / 1e9,
+        sol_refunded as f64 / 1e9
+    );
+
+    Ok(())
+}
+
+/// Event emitted when a creator cancels a launch
+#[event]
+pub struct LaunchCancelled {
+    pub launch: Pubkey,
+    pub mint: Pubkey,
+    pub creator: Pubkey,
+    /// Curve SOL returned to the creator
+    pub sol_refunded: u64,
+    /// Curve, graduation and creator-held tokens burned
+    pub tokens_burned: u64,
+    pub timestamp: i64,
+}
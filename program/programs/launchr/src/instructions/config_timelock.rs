@@ -0,0 +1,261 @@
+//! Launchr - Timelocked Config Changes
+//!
+//! Queue, execute and cancel config changes behind `Config.config_timelock_secs`.
+//! Pause flags stay instant through `update_config`; everything else waits
+//! out the delay so traders can react before fees or thresholds move.
+
+use anchor_lang::prelude::*;
+use crate::seeds::*;
+use crate::state::*;
+use crate::math::LaunchrError;
+use crate::instructions::init_config::{
+    apply_config_update, authorize_config_update, check_quote_mint_account,
+    validate_config_update, UpdateConfigParams,
+};
+use crate::instructions::multisig::authorize_admin_action;
+
+/// Queue a config change
+#[derive(Accounts)]
+pub struct QueueConfigChange<'info> {
+    /// Admin or holder of the roles required by the change
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    /// Global config
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump = config.bump
+    )]
+    pub config: Account<'info, Config>,
+
+    /// Delegated roles (required unless signed by the admin)
+    #[account(
+        seeds = [ROLES_SEED, config.key().as_ref()],
+        bump = roles.bump
+    )]
+    pub roles: Option<Account<'info, Roles>>,
+
+    /// Queued change (one at a time)
+    #[account(
+        init,
+        payer = authority,
+        space = PendingConfigChange::LEN,
+        seeds = [PENDING_CONFIG_SEED, config.key().as_ref()],
+        bump
+    )]
+    pub pending_change: Account<'info, PendingConfigChange>,
+
+    /// Admin multisig (required in multisig mode unless roles cover the change)
+    #[account(
+        seeds = [MULTISIG_SEED, config.key().as_ref()],
+        bump = multisig.bump
+    )]
+    pub multisig: Option<Account<'info, Multisig>>,
+
+    /// Approved proposal for this change (required alongside `multisig`)
+    #[account(mut)]
+    pub proposal: Option<Account<'info, Proposal>>,
+
+    /// System program
+    pub system_program: Program<'info, System>,
+}
+
+/// Queue a config change behind the timelock
+pub fn queue_config_change(ctx: Context<QueueConfigChange>, params: UpdateConfigParams) -> Result<()> {
+    authorize_config_update(
+        &ctx.accounts.config,
+        ctx.accounts.roles.as_deref(),
+        &ctx.accounts.authority.key(),
+        &params,
+        ctx.accounts.multisig.as_ref(),
+        ctx.accounts.proposal.as_mut(),
+        AdminAction::QueueConfigChange(params.clone()),
+    )?;
+    validate_config_update(&params)?;
+
+    let config = &ctx.accounts.config;
+    let pending_change = &mut ctx.accounts.pending_change;
+    let clock = Clock::get()?;
+
+    let eta = clock.unix_timestamp.saturating_add(config.config_timelock_secs as i64);
+
+    pending_change.config = config.key();
+    pending_change.proposer = ctx.accounts.authority.key();
+    pending_change.params = params.clone();
+    pending_change.queued_at = clock.unix_timestamp;
+    pending_change.eta = eta;
+    pending_change.bump = ctx.bumps.pending_change;
+
+    emit!(ConfigChangeQueued {
+        proposer: pending_change.proposer,
+        params,
+        eta,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Config change queued, executable at {}", eta);
+
+    Ok(())
+}
+
+/// Execute a queued config change
+#[derive(Accounts)]
+pub struct ExecuteConfigChange<'info> {
+    /// Anyone can execute once the timelock has elapsed
+    pub executor: Signer<'info>,
+
+    /// Global config
+    #[account(
+        mut,
+        seeds = [CONFIG_SEED],
+        bump = config.bump
+    )]
+    pub config: Account<'info, Config>,
+
+    /// Queued change (closed to proposer)
+    #[account(
+        mut,
+        close = proposer,
+        seeds = [PENDING_CONFIG_SEED, config.key().as_ref()],
+        bump = pending_change.bump
+    )]
+    pub pending_change: Account<'info, PendingConfigChange>,
+
+    /// Account that queued the change
+    /// CHECK: Validated against pending_change.proposer
+    #[account(
+        mut,
+        constraint = proposer.key() == pending_change.proposer @ LaunchrError::Unauthorized
+    )]
+    pub proposer: UncheckedAccount<'info>,
+
+    /// New quote mint (required when the change sets a quote mint)
+    pub new_quote_mint: Option<Account<'info, anchor_spl::token::Mint>>,
+}
+
+/// Apply a queued config change once its eta has passed
+pub fn execute_config_change(ctx: Context<ExecuteConfigChange>) -> Result<()> {
+    let pending_change = &ctx.accounts.pending_change;
+    let clock = Clock::get()?;
+
+    require!(
+        pending_change.is_ready(clock.unix_timestamp),
+        LaunchrError::TimelockNotElapsed
+    );
+
+    check_quote_mint_account(&pending_change.params, ctx.accounts.new_quote_mint.as_ref())?;
+
+    let params = pending_change.params.clone();
+    let eta = pending_change.eta;
+    let proposer = pending_change.proposer;
+    apply_config_update(&mut ctx.accounts.config, &params, proposer)?;
+
+    emit!(ConfigChangeExecuted {
+        proposer,
+        executor: ctx.accounts.executor.key(),
+        eta,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Queued config change executed");
+
+    Ok(())
+}
+
+/// Cancel a queued config change
+#[derive(Accounts)]
+pub struct CancelConfigChange<'info> {
+    /// Admin or the account that queued the change (admin only in multisig mode)
+    pub authority: Signer<'info>,
+
+    /// Global config
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump = config.bump
+    )]
+    pub config: Account<'info, Config>,
+
+    /// Queued change (closed to proposer)
+    #[account(
+        mut,
+        close = proposer,
+        seeds = [PENDING_CONFIG_SEED, config.key().as_ref()],
+        bump = pending_change.bump
+    )]
+    pub pending_change: Account<'info, PendingConfigChange>,
+
+    /// Account that queued the change
+    /// CHECK: Validated against pending_change.proposer
+    #[account(
+        mut,
+        constraint = proposer.key() == pending_change.proposer @ LaunchrError::Unauthorized
+    )]
+    pub proposer: UncheckedAccount<'info>,
+
+    /// Admin multisig (required in multisig mode)
+    #[account(
+        seeds = [MULTISIG_SEED, config.key().as_ref()],
+        bump = multisig.bump
+    )]
+    pub multisig: Option<Account<'info, Multisig>>,
+
+    /// Approved proposal (required alongside `multisig`)
+    #[account(mut)]
+    pub proposal: Option<Account<'info, Proposal>>,
+}
+
+/// Drop a queued config change
+pub fn cancel_config_change(ctx: Context<CancelConfigChange>) -> Result<()> {
+    let clock = Clock::get()?;
+
+    // The proposer can withdraw their own change, except in multisig mode
+    if !ctx.accounts.pending_change.proposer_may_cancel(&ctx.accounts.config, &ctx.accounts.authority.key()) {
+        authorize_admin_action(
+            &ctx.accounts.config,
+            &ctx.accounts.config.admin,
+            &ctx.accounts.authority.key(),
+            ctx.accounts.multisig.as_ref(),
+            ctx.accounts.proposal.as_mut(),
+            AdminAction::CancelConfigChange,
+        )?;
+    }
+
+    emit!(ConfigChangeCancelled {
+        proposer: ctx.accounts.pending_change.proposer,
+        cancelled_by: ctx.accounts.authority.key(),
+        eta: ctx.accounts.pending_change.eta,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Queued config change cancelled");
+
+    Ok(())
+}
+
+/// Event emitted when a config change is queued
+#[event]
+pub struct ConfigChangeQueued {
+    pub proposer: Pubkey,
+    pub params: UpdateConfigParams,
+    /// Unix timestamp after which the change can execute
+    pub eta: i64,
+    pub timestamp: i64,
+}
+
+/// Event emitted when a queued config change is applied
+#[event]
+pub struct ConfigChangeExecuted {
+    pub proposer: Pubkey,
+    pub executor: Pubkey,
+    pub eta: i64,
+    pub timestamp: i64,
+}
+
+/// Event emitted when a queued config change is dropped
+#[event]
+pub struct ConfigChangeCancelled {
+    pub proposer: Pubkey,
+    pub cancelled_by: Pubkey,
+    pub eta: i64,
+    pub timestamp: i64,
+}
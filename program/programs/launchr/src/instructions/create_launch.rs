@@ -1,12 +1,39 @@
 //! Launchr - Create Launch
 //! 
 //! Create a new token launch on the bonding curve.
+//! Token metadata is created through Metaplex so wallets and explorers
+//! show the launch's name, symbol and image.
+//!
+//! The mint is created without a freeze authority, and the mint authority is
+//! revoked once the full supply is minted and metadata is in place, so the
+//! supply is fixed at `allocation::TOTAL_SUPPLY`.
+//!
+//! With `initial_buy_lamports` the creator buys from the curve in the same
+//! instruction, so nobody can trade between creation and the creator's buy.
+//! The creator gets no exemption: the buy is held to the opening anti-sniper
+//! caps and the holding cap like any other.
+//!
+//! `create_launch_2022` is the Token-2022 variant: the mint carries the
+//! metadata-pointer and token-metadata extensions, so name, symbol and uri
+//! live on the mint itself and no Metaplex account is created.
 
 use anchor_lang::prelude::*;
-use anchor_spl::token::{self, Mint, Token, TokenAccount, MintTo};
+use anchor_lang::system_program;
+use anchor_spl::metadata::{self, mpl_token_metadata::types::DataV2, CreateMetadataAccountsV3, Metadata};
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token::Token;
+use anchor_spl::token_2022::Token2022;
+use anchor_spl::token_interface::{
+    self, Mint, MintTo, SetAuthority, TokenAccount, TokenMetadataInitialize,
+    TokenMetadataUpdateAuthority,
+};
+use anchor_spl::token_interface::spl_token_2022::instruction::AuthorityType;
+use anchor_spl::token_interface::spl_pod::optional_keys::OptionalNonZeroPubkey;
+use anchor_spl::token_interface::spl_token_metadata_interface::state::TokenMetadata;
 use crate::seeds::*;
 use crate::state::*;
-use crate::math::LaunchrError;
+use crate::math::{bonding_curve, LaunchrError, SwapResult};
+use crate::instructions::buy::{settle_buy, BuyAccounts};
 
 /// Create a new token launch
 #[derive(Accounts)]
@@ -25,15 +52,15 @@ pub struct CreateLaunch<'info> {
     )]
     pub config: Box<Account<'info, Config>>,
 
-    /// Token mint (created by this instruction)
+    /// Token mint (created by this instruction, no freeze authority)
     #[account(
         init,
         payer = creator,
         mint::decimals = 9,
         mint::authority = launch_authority,
-        mint::freeze_authority = launch_authority,
+        mint::token_program = token_program,
     )]
-    pub mint: Box<Account<'info, Mint>>,
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
     
     /// Launch account (PDA)
     #[account(
@@ -59,10 +86,11 @@ pub struct CreateLaunch<'info> {
         payer = creator,
         token::mint = mint,
         token::authority = launch_authority,
+        token::token_program = token_program,
         seeds = [TOKEN_VAULT_SEED, launch.key().as_ref()],
         bump
     )]
-    pub token_vault: Box<Account<'info, TokenAccount>>,
+    pub token_vault: Box<InterfaceAccount<'info, TokenAccount>>,
 
     /// LP reserve token vault (20% for Orbit DLMM migration)
     #[account(
@@ -70,17 +98,69 @@ pub struct CreateLaunch<'info> {
         payer = creator,
         token::mint = mint,
         token::authority = launch_authority,
+        token::token_program = token_program,
         seeds = [GRADUATION_VAULT_SEED, launch.key().as_ref()],
         bump
     )]
-    pub graduation_vault: Box<Account<'info, TokenAccount>>,
+    pub graduation_vault: Box<InterfaceAccount<'info, TokenAccount>>,
 
-    // Note: Creator receives 2 SOL reward on graduation, not token allocation
-    // No creator_token_account needed
+    /// SOL curve vault (receives the creator's initial buy)
+    /// CHECK: PDA for holding SOL
+    #[account(
+        mut,
+        seeds = [CURVE_VAULT_SEED, launch.key().as_ref()],
+        bump
+    )]
+    pub curve_vault: UncheckedAccount<'info>,
+
+    /// Fee vault for protocol fees on the creator's initial buy
+    /// CHECK: PDA for holding protocol fees
+    #[account(
+        mut,
+        seeds = [FEE_VAULT_SEED, config.key().as_ref()],
+        bump
+    )]
+    pub fee_vault: UncheckedAccount<'info>,
+
+    /// Creator's position (required with `initial_buy_lamports`)
+    #[account(
+        init,
+        payer = creator,
+        space = UserPosition::LEN,
+        seeds = [USER_POSITION_SEED, launch.key().as_ref(), creator.key().as_ref()],
+        bump
+    )]
+    pub creator_position: Option<Box<Account<'info, UserPosition>>>,
+
+    /// Creator's token account (required with `initial_buy_lamports`)
+    #[account(
+        init,
+        payer = creator,
+        associated_token::mint = mint,
+        associated_token::authority = creator,
+        associated_token::token_program = token_program,
+    )]
+    pub creator_token_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    /// Metaplex metadata account for the mint (created via CPI)
+    /// CHECK: PDA checked by seeds, initialized by the Token Metadata program
+    #[account(
+        mut,
+        seeds = [METADATA_SEED, token_metadata_program.key().as_ref(), mint.key().as_ref()],
+        seeds::program = token_metadata_program.key(),
+        bump
+    )]
+    pub metadata: UncheckedAccount<'info>,
 
     /// Token program
     pub token_program: Program<'info, Token>,
 
+    /// Metaplex Token Metadata program
+    pub token_metadata_program: Program<'info, Metadata>,
+
+    /// Associated token program
+    pub associated_token_program: Program<'info, AssociatedToken>,
+
     /// System program
     pub system_program: Program<'info, System>,
     
@@ -88,6 +168,126 @@ pub struct CreateLaunch<'info> {
     pub rent: Sysvar<'info, Rent>,
 }
 
+/// Create a new Token-2022 launch with on-mint metadata
+#[derive(Accounts)]
+#[instruction(params: CreateLaunchParams)]
+pub struct CreateLaunch2022<'info> {
+    /// Creator of the launch
+    #[account(mut)]
+    pub creator: Signer<'info>,
+
+    /// Global config
+    #[account(
+        mut,
+        seeds = [CONFIG_SEED],
+        bump = config.bump,
+        constraint = !config.launches_paused @ LaunchrError::LaunchesPaused
+    )]
+    pub config: Box<Account<'info, Config>>,
+
+    /// Token-2022 mint (created by this instruction, no freeze authority,
+    /// metadata pointer targets the mint itself)
+    #[account(
+        init,
+        payer = creator,
+        mint::decimals = 9,
+        mint::authority = launch_authority,
+        mint::token_program = token_program,
+        extensions::metadata_pointer::authority = launch_authority,
+        extensions::metadata_pointer::metadata_address = mint,
+    )]
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// Launch account (PDA)
+    #[account(
+        init,
+        payer = creator,
+        space = Launch::LEN,
+        seeds = [LAUNCH_SEED, mint.key().as_ref()],
+        bump
+    )]
+    pub launch: Box<Account<'info, Launch>>,
+
+    /// Launch authority PDA
+    /// CHECK: PDA checked by seeds
+    #[account(
+        seeds = [LAUNCH_AUTHORITY_SEED, launch.key().as_ref()],
+        bump
+    )]
+    pub launch_authority: UncheckedAccount<'info>,
+
+    /// Token vault for bonding curve tokens
+    #[account(
+        init,
+        payer = creator,
+        token::mint = mint,
+        token::authority = launch_authority,
+        token::token_program = token_program,
+        seeds = [TOKEN_VAULT_SEED, launch.key().as_ref()],
+        bump
+    )]
+    pub token_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// LP reserve token vault (20% for Orbit DLMM migration)
+    #[account(
+        init,
+        payer = creator,
+        token::mint = mint,
+        token::authority = launch_authority,
+        token::token_program = token_program,
+        seeds = [GRADUATION_VAULT_SEED, launch.key().as_ref()],
+        bump
+    )]
+    pub graduation_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// SOL curve vault (receives the creator's initial buy)
+    /// CHECK: PDA for holding SOL
+    #[account(
+        mut,
+        seeds = [CURVE_VAULT_SEED, launch.key().as_ref()],
+        bump
+    )]
+    pub curve_vault: UncheckedAccount<'info>,
+
+    /// Fee vault for protocol fees on the creator's initial buy
+    /// CHECK: PDA for holding protocol fees
+    #[account(
+        mut,
+        seeds = [FEE_VAULT_SEED, config.key().as_ref()],
+        bump
+    )]
+    pub fee_vault: UncheckedAccount<'info>,
+
+    /// Creator's position (required with `initial_buy_lamports`)
+    #[account(
+        init,
+        payer = creator,
+        space = UserPosition::LEN,
+        seeds = [USER_POSITION_SEED, launch.key().as_ref(), creator.key().as_ref()],
+        bump
+    )]
+    pub creator_position: Option<Box<Account<'info, UserPosition>>>,
+
+    /// Creator's token account (required with `initial_buy_lamports`)
+    #[account(
+        init,
+        payer = creator,
+        associated_token::mint = mint,
+        associated_token::authority = creator,
+        associated_token::token_program = token_program,
+    )]
+    pub creator_token_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    /// Token-2022 program
+    pub token_program: Program<'info, Token2022>,
+
+    /// Associated token program
+    pub associated_token_program: Program<'info, AssociatedToken>,
+
+    /// System program
+    pub system_program: Program<'info, System>,
+}
+
 /// Parameters for creating a launch
 #[derive(AnchorSerialize, AnchorDeserialize, Clone)]
 pub struct CreateLaunchParams {
@@ -106,6 +306,50 @@ pub struct CreateLaunchParams {
     /// Creator fee in basis points (ignored - fixed at 0.2%)
     #[deprecated(note = "Creator fee is now fixed at 0.2%. This field is ignored.")]
     pub creator_fee_bps: u16,
+    /// SOL the creator spends buying from the curve in the same instruction (optional)
+    pub initial_buy_lamports: Option<u64>,
+    /// Minimum tokens the initial buy must return (slippage protection)
+    pub min_tokens_out: u64,
+    /// Unix timestamp from which trading opens (optional, defaults to now)
+    pub trading_starts_at: Option<i64>,
+    /// Anti-sniper window override in seconds (optional, defaults to config)
+    pub sniper_window_secs: Option<u32>,
+    /// Anti-sniper per-transaction buy cap override (optional, BPS of threshold)
+    pub sniper_max_buy_bps: Option<u16>,
+    /// Anti-sniper per-wallet token cap override (optional, BPS of supply)
+    pub sniper_max_wallet_bps: Option<u16>,
+    /// Holding cap per wallet while on the curve (optional, BPS of supply)
+    pub max_wallet_bps: Option<u16>,
+}
+
+impl CreateLaunchParams {
+    /// Validate string lengths
+    pub fn validate(&self) -> Result<()> {
+        require!(self.name.len() <= 32, LaunchrError::InvalidConfig);
+        require!(self.symbol.len() <= 10, LaunchrError::InvalidConfig);
+        require!(self.uri.len() <= 200, LaunchrError::InvalidConfig);
+        // creator_fee_bps is ignored - always fixed at 0.2%
+        if let Some(window_secs) = self.sniper_window_secs {
+            require!(window_secs <= limits::MAX_SNIPER_WINDOW_SECS, LaunchrError::InvalidConfig);
+        }
+        for cap_bps in [self.sniper_max_buy_bps, self.sniper_max_wallet_bps, self.max_wallet_bps]
+            .into_iter()
+            .flatten()
+        {
+            require!(cap_bps <= limits::MAX_SNIPER_CAP_BPS, LaunchrError::InvalidConfig);
+        }
+        Ok(())
+    }
+    
+    /// Resolve the trading start, bounded by the config's maximum delay
+    pub fn trading_start(&self, config: &Config, now: i64) -> Result<i64> {
+        let Some(starts_at) = self.trading_starts_at else {
+            return Ok(now);
+        };
+        let latest = now.saturating_add(config.max_trading_start_delay() as i64);
+        require!(starts_at <= latest, LaunchrError::TradingStartTooLate);
+        Ok(starts_at.max(now))
+    }
 }
 
 /// Creator fee: 0.2% (20 bps) - fixed, taken from the 1% protocol fee
@@ -113,19 +357,291 @@ pub const CREATOR_FEE_BPS: u16 = 20;
 
 /// Create a new token launch
 pub fn create_launch(ctx: Context<CreateLaunch>, params: CreateLaunchParams) -> Result<()> {
-    // Validate parameters
-    require!(params.name.len() <= 32, LaunchrError::InvalidConfig);
-    require!(params.symbol.len() <= 10, LaunchrError::InvalidConfig);
-    require!(params.uri.len() <= 200, LaunchrError::InvalidConfig);
-    // creator_fee_bps is ignored - always fixed at 0.2%
-    
-    let launch = &mut ctx.accounts.launch;
-    let config = &mut ctx.accounts.config;
+    let creator = ctx.accounts.creator.to_account_info();
+    let mint = ctx.accounts.mint.to_account_info();
+    let launch_authority = ctx.accounts.launch_authority.to_account_info();
+    let metadata = ctx.accounts.metadata.to_account_info();
+    let token_metadata_program = ctx.accounts.token_metadata_program.to_account_info();
+    let system_program = ctx.accounts.system_program.to_account_info();
+    let rent = ctx.accounts.rent.to_account_info();
+
+    let accounts = LaunchAccounts {
+        creator: creator.clone(),
+        config: &mut ctx.accounts.config,
+        launch: &mut ctx.accounts.launch,
+        launch_bump: ctx.bumps.launch,
+        launch_authority: launch_authority.clone(),
+        authority_bump: ctx.bumps.launch_authority,
+        mint: mint.clone(),
+        mint_decimals: ctx.accounts.mint.decimals,
+        token_vault: ctx.accounts.token_vault.to_account_info(),
+        graduation_vault: ctx.accounts.graduation_vault.to_account_info(),
+        curve_vault: ctx.accounts.curve_vault.to_account_info(),
+        fee_vault: ctx.accounts.fee_vault.to_account_info(),
+        creator_position: ctx.accounts.creator_position.as_deref_mut(),
+        creator_position_bump: ctx.bumps.creator_position.unwrap_or_default(),
+        creator_token_account: ctx.accounts.creator_token_account.as_ref().map(|account| account.to_account_info()),
+        token_program: ctx.accounts.token_program.to_account_info(),
+        system_program: system_program.clone(),
+    };
+
+    // ========== Token Metadata ==========
+    // The launch authority signs as mint authority; update authority follows config policy
+    init_launch(accounts, params, |params, metadata_policy, signer_seeds| {
+        let update_authority = match metadata_policy {
+            MetadataPolicy::Creator => creator.clone(),
+            MetadataPolicy::LockAtGraduation | MetadataPolicy::Immutable => launch_authority.clone(),
+        };
+
+        metadata::create_metadata_accounts_v3(
+            CpiContext::new_with_signer(
+                token_metadata_program,
+                CreateMetadataAccountsV3 {
+                    metadata: metadata.clone(),
+                    mint,
+                    mint_authority: launch_authority,
+                    payer: creator,
+                    update_authority,
+                    system_program,
+                    rent,
+                },
+                signer_seeds,
+            ),
+            DataV2 {
+                name: params.name.clone(),
+                symbol: params.symbol.clone(),
+                uri: params.uri.clone(),
+                seller_fee_basis_points: 0,
+                creators: None,
+                collection: None,
+                uses: None,
+            },
+            metadata_policy != MetadataPolicy::Immutable,
+            true,
+            None,
+        )?;
+
+        msg!("Metadata: {} (policy: {:?})", metadata.key(), metadata_policy);
+        Ok(())
+    })
+}
+
+/// Create a new Token-2022 launch
+pub fn create_launch_2022(ctx: Context<CreateLaunch2022>, params: CreateLaunchParams) -> Result<()> {
+    let creator = ctx.accounts.creator.to_account_info();
+    let mint = ctx.accounts.mint.to_account_info();
+    let launch_authority = ctx.accounts.launch_authority.to_account_info();
+    let token_program = ctx.accounts.token_program.to_account_info();
+    let system_program = ctx.accounts.system_program.to_account_info();
+
+    let accounts = LaunchAccounts {
+        creator: creator.clone(),
+        config: &mut ctx.accounts.config,
+        launch: &mut ctx.accounts.launch,
+        launch_bump: ctx.bumps.launch,
+        launch_authority: launch_authority.clone(),
+        authority_bump: ctx.bumps.launch_authority,
+        mint: mint.clone(),
+        mint_decimals: ctx.accounts.mint.decimals,
+        token_vault: ctx.accounts.token_vault.to_account_info(),
+        graduation_vault: ctx.accounts.graduation_vault.to_account_info(),
+        curve_vault: ctx.accounts.curve_vault.to_account_info(),
+        fee_vault: ctx.accounts.fee_vault.to_account_info(),
+        creator_position: ctx.accounts.creator_position.as_deref_mut(),
+        creator_position_bump: ctx.bumps.creator_position.unwrap_or_default(),
+        creator_token_account: ctx.accounts.creator_token_account.as_ref().map(|account| account.to_account_info()),
+        token_program: token_program.clone(),
+        system_program: system_program.clone(),
+    };
+
+    // ========== Token Metadata (mint extension) ==========
+    init_launch(accounts, params, |params, metadata_policy, signer_seeds| {
+        let update_authority = match metadata_policy {
+            MetadataPolicy::Creator => creator.clone(),
+            MetadataPolicy::LockAtGraduation | MetadataPolicy::Immutable => launch_authority.clone(),
+        };
+
+        // Token-2022 grows the mint for the metadata entry, but the rent must already be there
+        let token_metadata = TokenMetadata {
+            update_authority: OptionalNonZeroPubkey::try_from(Some(update_authority.key()))?,
+            mint: mint.key(),
+            name: params.name.clone(),
+            symbol: params.symbol.clone(),
+            uri: params.uri.clone(),
+            additional_metadata: Vec::new(),
+        };
+        let metadata_rent = Rent::get()?
+            .minimum_balance(mint.data_len() + token_metadata.tlv_size_of()?)
+            .saturating_sub(mint.lamports());
+        if metadata_rent > 0 {
+            system_program::transfer(
+                CpiContext::new(
+                    system_program,
+                    system_program::Transfer {
+                        from: creator,
+                        to: mint.clone(),
+                    },
+                ),
+                metadata_rent,
+            )?;
+        }
+
+        token_interface::token_metadata_initialize(
+            CpiContext::new_with_signer(
+                token_program.clone(),
+                TokenMetadataInitialize {
+                    program_id: token_program.clone(),
+                    metadata: mint.clone(),
+                    update_authority,
+                    mint_authority: launch_authority.clone(),
+                    mint: mint.clone(),
+                },
+                signer_seeds,
+            ),
+            token_metadata.name,
+            token_metadata.symbol,
+            token_metadata.uri,
+        )?;
+
+        if metadata_policy == MetadataPolicy::Immutable {
+            token_interface::token_metadata_update_authority(
+                CpiContext::new_with_signer(
+                    token_program.clone(),
+                    TokenMetadataUpdateAuthority {
+                        program_id: token_program,
+                        metadata: mint,
+                        current_authority: launch_authority.clone(),
+                        new_authority: launch_authority,
+                    },
+                    signer_seeds,
+                ),
+                OptionalNonZeroPubkey::default(),
+            )?;
+        }
+
+        msg!("Metadata: on mint (policy: {:?})", metadata_policy);
+        Ok(())
+    })
+}
+
+/// Accounts both launch instructions work with, whichever token program backs the mint
+struct LaunchAccounts<'a, 'info> {
+    creator: AccountInfo<'info>,
+    config: &'a mut Account<'info, Config>,
+    launch: &'a mut Account<'info, Launch>,
+    launch_bump: u8,
+    launch_authority: AccountInfo<'info>,
+    authority_bump: u8,
+    mint: AccountInfo<'info>,
+    mint_decimals: u8,
+    token_vault: AccountInfo<'info>,
+    graduation_vault: AccountInfo<'info>,
+    curve_vault: AccountInfo<'info>,
+    fee_vault: AccountInfo<'info>,
+    creator_position: Option<&'a mut Account<'info, UserPosition>>,
+    creator_position_bump: u8,
+    creator_token_account: Option<AccountInfo<'info>>,
+    token_program: AccountInfo<'info>,
+    system_program: AccountInfo<'info>,
+}
+
+/// Set up a launch: state, supply, metadata, and the creator's initial buy
+///
+/// `create_metadata` runs while the launch authority still holds the mint
+/// authority; it is revoked right after, which fixes the supply.
+fn init_launch<'info>(
+    mut accounts: LaunchAccounts<'_, 'info>,
+    params: CreateLaunchParams,
+    create_metadata: impl FnOnce(&CreateLaunchParams, MetadataPolicy, &[&[&[u8]]]) -> Result<()>,
+) -> Result<()> {
+    params.validate()?;
+    let initial_buy_lamports = initial_buy_amount(
+        params.initial_buy_lamports,
+        accounts.creator_position.is_some() && accounts.creator_token_account.is_some(),
+    )?;
     let clock = Clock::get()?;
+
+    let launch = &mut *accounts.launch;
+    let config = &mut *accounts.config;
+    launch.mint = accounts.mint.key();
+    launch.creator = accounts.creator.key();
+    launch.bump = accounts.launch_bump;
+    launch.authority_bump = accounts.authority_bump;
+    init_launch_state(launch, config, &params, clock.unix_timestamp)?;
+
+    // Mint tokens
+    let launch_key = launch.key();
+    let authority_seeds: &[&[u8]] = &[
+        LAUNCH_AUTHORITY_SEED,
+        launch_key.as_ref(),
+        &[launch.authority_bump],
+    ];
+    let signer_seeds = &[authority_seeds];
+
+    let curve_tokens = launch.real_token_reserve;
+    for (vault, amount) in [
+        (accounts.token_vault.clone(), curve_tokens),
+        (accounts.graduation_vault.clone(), launch.graduation_tokens),
+    ] {
+        mint_to_vault(
+            accounts.token_program.clone(),
+            accounts.mint.clone(),
+            vault,
+            accounts.launch_authority.clone(),
+            signer_seeds,
+            amount,
+        )?;
+    }
+
+    create_metadata(&params, config.metadata_policy, signer_seeds)?;
+
+    // Metadata no longer needs the mint authority - fix the supply
+    revoke_mint_authority(
+        accounts.token_program.clone(),
+        accounts.mint.clone(),
+        accounts.launch_authority.clone(),
+        signer_seeds,
+        AuthorityType::MintTokens,
+    )?;
+    
+    // Update global stats
+    config.record_launch();
+    
+    // Log before emitting (since emit moves the values)
+    msg!("🚀 Launch created: {} ({})", params.name, params.symbol);
+
+    // Emit event
+    emit!(LaunchCreated {
+        mint: launch.mint,
+        creator: launch.creator,
+        name: params.name,
+        symbol: params.symbol,
+        total_supply: launch.total_supply,
+        graduation_threshold: launch.graduation_threshold,
+        trading_starts_at: launch.trading_starts_at,
+        timestamp: clock.unix_timestamp,
+    });
+    msg!("Mint: {}", launch.mint);
+    msg!("Bonding curve: {} tokens (80%)", curve_tokens);
+    msg!("LP reserve: {} tokens (20%)", launch.graduation_tokens);
+
+    // ========== Creator Initial Buy ==========
+    if let Some(sol_amount) = initial_buy_lamports {
+        creator_initial_buy(&mut accounts, sol_amount, params.min_tokens_out)?;
+    }
     
-    // Initialize launch state
-    launch.mint = ctx.accounts.mint.key();
-    launch.creator = ctx.accounts.creator.key();
+    Ok(())
+}
+
+/// Initialize launch state shared by both token programs
+///
+/// Expects `mint`, `creator` and bumps to be set by the caller.
+fn init_launch_state(
+    launch: &mut Launch,
+    config: &Config,
+    params: &CreateLaunchParams,
+    now: i64,
+) -> Result<()> {
     launch.status = LaunchStatus::Active;
     
     // Token allocation (80% bonding curve, 20% LP reserve)
@@ -134,25 +650,37 @@ pub fn create_launch(ctx: Context<CreateLaunch>, params: CreateLaunchParams) ->
     launch.graduation_tokens = allocation::lp_reserve_tokens(); // 20% for LP migration
 
     // Bonding curve initial state
-    let curve_tokens = allocation::curve_tokens(); // 80%
     launch.tokens_sold = 0;
     launch.virtual_sol_reserve = curve_params::INITIAL_VIRTUAL_SOL;
     launch.virtual_token_reserve = curve_params::INITIAL_VIRTUAL_TOKENS;
     launch.real_sol_reserve = 0;
-    launch.real_token_reserve = curve_tokens;
+    launch.real_token_reserve = allocation::curve_tokens(); // 80%
     
     // Thresholds
     launch.graduation_threshold = config.graduation_threshold;
+    launch.refund_window_secs = config.refund_window_secs;
+    // A config that never set the shares keeps new launches on the fixed payouts too
+    launch.graduation_creator_reward_bps = config.graduation_creator_reward_bps;
+    launch.graduation_treasury_fee_bps = config.graduation_treasury_fee_bps;
+    launch.graduation_payouts_set = config.graduation_payouts_set;
     
     // Timestamps
-    launch.created_at = clock.unix_timestamp;
+    launch.created_at = now;
     launch.graduated_at = 0;
+    launch.trading_starts_at = params.trading_start(config, now)?;
+    
+    // Anti-sniper window (per-launch overrides fall back to config)
+    launch.sniper_window_secs = params.sniper_window_secs.unwrap_or(config.sniper_window_secs);
+    launch.sniper_max_buy_bps = params.sniper_max_buy_bps.unwrap_or(config.sniper_max_buy_bps);
+    launch.sniper_max_wallet_bps = params.sniper_max_wallet_bps.unwrap_or(config.sniper_max_wallet_bps);
+    launch.max_wallet_bps = params.max_wallet_bps.unwrap_or(0);
     
     // Statistics
     launch.buy_volume = 0;
     launch.sell_volume = 0;
     launch.trade_count = 0;
-    launch.holder_count = 1; // Creator
+    launch.holder_count = 0; // Counted as positions take on tokens
+    launch.holders_tracked = true;
     
     // Fees - fixed at 0.2% (creator_fee_bps param is ignored)
     launch.creator_fee_bps = CREATOR_FEE_BPS;
@@ -196,71 +724,144 @@ pub fn create_launch(ctx: Context<CreateLaunch>, params: CreateLaunchParams) ->
         launch.website[..len].copy_from_slice(&src[..len]);
     }
     
-    // Store bumps
-    launch.bump = ctx.bumps.launch;
-    launch.authority_bump = ctx.bumps.launch_authority;
-    
-    // Mint tokens
-    let launch_key = launch.key();
-    let authority_seeds: &[&[u8]] = &[
-        LAUNCH_AUTHORITY_SEED,
-        launch_key.as_ref(),
-        &[launch.authority_bump],
-    ];
-    let signer_seeds = &[authority_seeds];
-    
-    // Mint to bonding curve vault (80% - sold on curve)
-    token::mint_to(
+    Ok(())
+}
+
+/// Mint `amount` launch tokens into a vault, signed by the launch authority
+fn mint_to_vault<'info>(
+    token_program: AccountInfo<'info>,
+    mint: AccountInfo<'info>,
+    vault: AccountInfo<'info>,
+    launch_authority: AccountInfo<'info>,
+    signer_seeds: &[&[&[u8]]],
+    amount: u64,
+) -> Result<()> {
+    token_interface::mint_to(
         CpiContext::new_with_signer(
-            ctx.accounts.token_program.to_account_info(),
+            token_program,
             MintTo {
-                mint: ctx.accounts.mint.to_account_info(),
-                to: ctx.accounts.token_vault.to_account_info(),
-                authority: ctx.accounts.launch_authority.to_account_info(),
+                mint,
+                to: vault,
+                authority: launch_authority,
             },
             signer_seeds,
         ),
-        curve_tokens,
-    )?;
+        amount,
+    )
+}
 
-    // Mint to LP reserve vault (20% - reserved for Orbit DLMM migration)
-    token::mint_to(
+/// Remove a mint authority held by the launch authority
+pub(crate) fn revoke_mint_authority<'info>(
+    token_program: AccountInfo<'info>,
+    mint: AccountInfo<'info>,
+    launch_authority: AccountInfo<'info>,
+    signer_seeds: &[&[&[u8]]],
+    authority_type: AuthorityType,
+) -> Result<()> {
+    token_interface::set_authority(
         CpiContext::new_with_signer(
-            ctx.accounts.token_program.to_account_info(),
-            MintTo {
-                mint: ctx.accounts.mint.to_account_info(),
-                to: ctx.accounts.graduation_vault.to_account_info(),
-                authority: ctx.accounts.launch_authority.to_account_info(),
+            token_program,
+            SetAuthority {
+                current_authority: launch_authority,
+                account_or_mint: mint,
             },
             signer_seeds,
         ),
-        launch.graduation_tokens,
+        authority_type,
+        None,
+    )
+}
+
+/// Buy from the fresh curve for the creator, in the launch transaction so
+/// no one can trade in between
+fn creator_initial_buy(
+    accounts: &mut LaunchAccounts,
+    sol_amount: u64,
+    min_tokens_out: u64,
+) -> Result<()> {
+    let (Some(creator_position), Some(creator_token_account)) = (
+        accounts.creator_position.as_deref_mut(),
+        accounts.creator_token_account.clone(),
+    ) else {
+        return err!(LaunchrError::InvalidConfig);
+    };
+
+    let swap_result = quote_initial_buy(
+        accounts.launch,
+        accounts.config.protocol_fee_bps,
+        sol_amount,
+        min_tokens_out,
     )?;
 
-    // Note: Creator receives SOL reward (2 SOL) on graduation, not token allocation
-    
-    // Update global stats
-    config.record_launch();
-    
-    // Log before emitting (since emit moves the values)
-    msg!("🚀 Launch created: {} ({})", params.name, params.symbol);
+    settle_buy(
+        BuyAccounts {
+            buyer: accounts.creator.clone(),
+            buyer_token_account: creator_token_account,
+            buyer_token_balance: 0, // Created by this instruction
+            creator: accounts.creator.clone(),
+            config: accounts.config,
+            launch: accounts.launch,
+            launch_authority: accounts.launch_authority.clone(),
+            user_position: creator_position,
+            user_position_bump: accounts.creator_position_bump,
+            mint: accounts.mint.clone(),
+            mint_decimals: accounts.mint_decimals,
+            token_vault: accounts.token_vault.clone(),
+            curve_vault: accounts.curve_vault.clone(),
+            fee_vault: accounts.fee_vault.clone(),
+            token_program: accounts.token_program.clone(),
+            system_program: accounts.system_program.clone(),
+        },
+        sol_amount,
+        sol_amount,
+        swap_result.amount_out,
+        &swap_result,
+    )
+}
 
-    // Emit event
-    emit!(LaunchCreated {
-        mint: launch.mint,
-        creator: launch.creator,
-        name: params.name,
-        symbol: params.symbol,
-        total_supply: launch.total_supply,
-        graduation_threshold: launch.graduation_threshold,
-        timestamp: clock.unix_timestamp,
-    });
-    msg!("Mint: {}", launch.mint);
-    msg!("Bonding curve: {} tokens (80%)", curve_tokens);
-    msg!("LP reserve: {} tokens (20%)", launch.graduation_tokens);
-    msg!("Creator receives: 2 SOL reward on graduation");
-    
-    Ok(())
+/// SOL for the creator's initial buy, if any
+///
+/// An initial buy needs the creator's position and token account.
+fn initial_buy_amount(initial_buy_lamports: Option<u64>, has_buy_accounts: bool) -> Result<Option<u64>> {
+    let initial_buy_lamports = initial_buy_lamports.filter(|&amount| amount > 0);
+    require!(
+        initial_buy_lamports.is_none() || has_buy_accounts,
+        LaunchrError::InvalidConfig
+    );
+    Ok(initial_buy_lamports)
+}
+
+/// Price the creator's initial buy against a launch's fresh curve
+///
+/// Unlike `buy`, an initial buy past the graduation threshold is rejected
+/// rather than capped, since the creator picks the amount with the launch.
+fn quote_initial_buy(
+    launch: &Launch,
+    protocol_fee_bps: u16,
+    sol_amount: u64,
+    min_tokens_out: u64,
+) -> Result<SwapResult> {
+    let swap_result = bonding_curve::calculate_buy(
+        sol_amount,
+        launch.virtual_sol_reserve,
+        launch.virtual_token_reserve,
+        protocol_fee_bps,
+        launch.creator_fee_bps,
+    )?;
+    require!(
+        swap_result.amount_out >= min_tokens_out,
+        LaunchrError::SlippageExceeded
+    );
+
+    let sol_to_vault = sol_amount
+        .saturating_sub(swap_result.protocol_fee)
+        .saturating_sub(swap_result.creator_fee);
+    require!(
+        sol_to_vault <= launch.sol_to_threshold(),
+        LaunchrError::GraduationThresholdExceeded
+    );
+
+    Ok(swap_result)
 }
 
 /// Event emitted when a launch is created
@@ -272,5 +873,57 @@ pub struct LaunchCreated {
     pub symbol: String,
     pub total_supply: u64,
     pub graduation_threshold: u64,
+    pub trading_starts_at: i64,
     pub timestamp: i64,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fresh_launch() -> Launch {
+        Launch {
+            status: LaunchStatus::Active,
+            total_supply: allocation::TOTAL_SUPPLY,
+            virtual_sol_reserve: curve_params::INITIAL_VIRTUAL_SOL,
+            virtual_token_reserve: curve_params::INITIAL_VIRTUAL_TOKENS,
+            real_token_reserve: allocation::curve_tokens(),
+            graduation_threshold: 85_000_000_000,
+            creator_fee_bps: CREATOR_FEE_BPS,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_initial_buy_quote_checks_slippage_and_threshold() {
+        let launch = fresh_launch();
+        let swap_result = quote_initial_buy(&launch, 100, 1_000_000_000, 0).unwrap();
+        assert!(swap_result.amount_out > 0);
+        assert!(quote_initial_buy(&launch, 100, 1_000_000_000, swap_result.amount_out).is_ok());
+
+        assert_eq!(
+            quote_initial_buy(&launch, 100, 1_000_000_000, swap_result.amount_out + 1).unwrap_err(),
+            LaunchrError::SlippageExceeded.into()
+        );
+
+        // Past the threshold the initial buy fails instead of being capped
+        assert_eq!(
+            quote_initial_buy(&launch, 100, 100_000_000_000, 0).unwrap_err(),
+            LaunchrError::GraduationThresholdExceeded.into()
+        );
+    }
+
+    #[test]
+    fn test_initial_buy_needs_creator_accounts() {
+        assert_eq!(initial_buy_amount(None, false).unwrap(), None);
+
+        // A zero amount is no initial buy
+        assert_eq!(initial_buy_amount(Some(0), false).unwrap(), None);
+
+        assert_eq!(initial_buy_amount(Some(1_000_000_000), true).unwrap(), Some(1_000_000_000));
+        assert_eq!(
+            initial_buy_amount(Some(1_000_000_000), false).unwrap_err(),
+            LaunchrError::InvalidConfig.into()
+        );
+    }
+}
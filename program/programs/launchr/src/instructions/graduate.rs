@@ -3,10 +3,25 @@
 //! Graduate a launch from the bonding curve to Orbit Finance DLMM liquidity.
 //! "Launch into Orbit" - the final step of the Launchr journey.
 //!
-//! ## Graduation Distribution (85 SOL threshold)
-//! - 80 SOL → Orbit Finance DLMM LP (paired with 20% token reserve = 200M tokens)
-//! - 2 SOL  → Token creator reward
-//! - 3 SOL  → Launchr treasury
+//! ## Graduation Distribution
+//! Payouts are shares of the launch's graduation threshold, snapshotted from
+//! config at creation (defaults 1.9975 SOL and 3.0005 SOL of an 85 SOL threshold;
+//! launches from before the shares existed keep the fixed 2 SOL and 3 SOL):
+//! - Creator reward → Token creator
+//! - Treasury fee   → Launchr treasury
+//! - Remainder      → Orbit Finance DLMM LP (paired with 20% token reserve = 200M tokens)
+//!
+//! ## Metadata Lock
+//! Metadata still controlled by the launch authority is made immutable on graduation,
+//! whether it lives in a Metaplex account or in the Token-2022 metadata extension.
+//!
+//! ## Token Programs
+//! Orbit's published IDL takes one token program for both sides of the pool,
+//! so graduation requires the launch mint and the quote mint to share it.
+//!
+//! ## Fixed Supply
+//! Any mint or freeze authority still held by the launch authority (launches
+//! created before authorities were revoked at creation) is revoked here.
 //!
 //! ## LP Burning (PDA-Locked)
 //! The LP position is created with the launch_authority PDA as owner. Since:
@@ -21,11 +36,22 @@
 use anchor_lang::prelude::*;
 use anchor_lang::solana_program::instruction::Instruction;
 use anchor_lang::solana_program::program::invoke_signed;
-use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+use anchor_lang::solana_program::program_option::COption;
+use anchor_lang::system_program;
+use anchor_spl::metadata::{self, mpl_token_metadata, Metadata, UpdateMetadataAccountsV2};
+use anchor_spl::token::Token;
+use anchor_spl::token_interface::{
+    self, Mint, TokenAccount, TokenInterface, TokenMetadataUpdateAuthority, TransferChecked,
+};
+use anchor_spl::token_interface::spl_pod::optional_keys::OptionalNonZeroPubkey;
+use anchor_spl::token_interface::spl_token_2022::instruction::AuthorityType;
+use anchor_spl::token_interface::spl_token_2022::extension::{BaseStateWithExtensions, StateWithExtensions};
+use anchor_spl::token_interface::spl_token_2022::state::Mint as MintState;
+use anchor_spl::token_interface::spl_token_metadata_interface::state::TokenMetadata;
 use crate::seeds::*;
 use crate::state::*;
-use crate::state::launch::graduation;
 use crate::math::{orbit_math, LaunchrError};
+use crate::instructions::create_launch::revoke_mint_authority;
 
 /// Graduate a launch to Orbit Finance DLMM
 #[derive(Accounts)]
@@ -48,7 +74,9 @@ pub struct Graduate<'info> {
         seeds = [LAUNCH_SEED, launch.mint.as_ref()],
         bump = launch.bump,
         constraint = launch.can_graduate() @ LaunchrError::AlreadyGraduated,
-        constraint = launch.threshold_reached() @ LaunchrError::ThresholdNotReached
+        constraint = launch.threshold_reached() @ LaunchrError::ThresholdNotReached,
+        constraint = !launch.is_paused() @ LaunchrError::LaunchPaused,
+        constraint = !launch.is_delisted() @ LaunchrError::LaunchDelisted
     )]
     pub launch: Box<Account<'info, Launch>>,
 
@@ -79,12 +107,14 @@ pub struct Graduate<'info> {
     /// Token mint
     #[account(
         mut,
+        mint::token_program = token_program,
         constraint = mint.key() == launch.mint @ LaunchrError::InvalidConfig
     )]
-    pub mint: Box<Account<'info, anchor_spl::token::Mint>>,
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
 
     /// Quote mint (WSOL)
     #[account(
+        mint::token_program = quote_token_program,
         constraint = quote_mint.key() == config.quote_mint @ LaunchrError::InvalidConfig
     )]
     pub quote_mint: Box<Account<'info, anchor_spl::token::Mint>>,
@@ -96,7 +126,7 @@ pub struct Graduate<'info> {
         bump,
         constraint = token_vault.mint == launch.mint @ LaunchrError::InvalidConfig
     )]
-    pub token_vault: Account<'info, TokenAccount>,
+    pub token_vault: Box<InterfaceAccount<'info, TokenAccount>>,
 
     /// LP reserve token vault (20% for DLMM migration)
     #[account(
@@ -105,7 +135,7 @@ pub struct Graduate<'info> {
         bump,
         constraint = graduation_vault.mint == launch.mint @ LaunchrError::InvalidConfig
     )]
-    pub graduation_vault: Account<'info, TokenAccount>,
+    pub graduation_vault: Box<InterfaceAccount<'info, TokenAccount>>,
 
     /// SOL curve vault
     /// CHECK: PDA holding SOL
@@ -175,8 +205,24 @@ pub struct Graduate<'info> {
     #[account(mut)]
     pub orbit_position: UncheckedAccount<'info>,
     
-    /// Token program
-    pub token_program: Program<'info, Token>,
+    /// Metaplex metadata account for the mint
+    /// CHECK: PDA checked by seeds, parsed in the handler (empty for legacy and Token-2022 launches)
+    #[account(
+        mut,
+        seeds = [METADATA_SEED, token_metadata_program.key().as_ref(), mint.key().as_ref()],
+        seeds::program = token_metadata_program.key(),
+        bump
+    )]
+    pub metadata: UncheckedAccount<'info>,
+    
+    /// Token program (SPL Token or Token-2022, matching the mint)
+    pub token_program: Interface<'info, TokenInterface>,
+    
+    /// Token program of the quote mint (WSOL is always SPL Token)
+    pub quote_token_program: Program<'info, Token>,
+    
+    /// Metaplex Token Metadata program
+    pub token_metadata_program: Program<'info, Metadata>,
     
     /// System program
     pub system_program: Program<'info, System>,
@@ -250,6 +296,20 @@ pub fn graduate(ctx: Context<Graduate>, params: GraduateParams) -> Result<()> {
     msg!("Canonical ordering - Base: {}, Quote: {}", base_mint, quote_mint);
     msg!("Is inverted: {}", is_inverted);
 
+    // Token programs follow the same ordering as the mints. Orbit's IDL takes a
+    // single token program for both sides, so both mints must share it.
+    let (base_token_program, quote_token_program) = if is_inverted {
+        (ctx.accounts.quote_token_program.to_account_info(), ctx.accounts.token_program.to_account_info())
+    } else {
+        (ctx.accounts.token_program.to_account_info(), ctx.accounts.quote_token_program.to_account_info())
+    };
+    require_keys_eq!(
+        base_token_program.key(),
+        quote_token_program.key(),
+        LaunchrError::OrbitTokenProgramMismatch
+    );
+    let orbit_token_program = base_token_program;
+
     // Build authority signer seeds
     let launch_key = launch.key();
     let authority_seeds: &[&[u8]] = &[
@@ -260,7 +320,7 @@ pub fn graduate(ctx: Context<Graduate>, params: GraduateParams) -> Result<()> {
     let signer_seeds = &[authority_seeds];
 
     // ========== SOL Distribution ==========
-    // Total: 85 SOL = 80 SOL (LP) + 2 SOL (Creator) + 3 SOL (Treasury)
+    // Creator reward + treasury fee come off the threshold; the rest goes to LP
 
     let curve_vault_lamports = ctx.accounts.curve_vault.lamports();
     msg!("Curve vault balance: {} lamports ({} SOL)",
@@ -270,21 +330,42 @@ pub fn graduate(ctx: Context<Graduate>, params: GraduateParams) -> Result<()> {
 
     // Verify we have enough SOL for distribution
     require!(
-        curve_vault_lamports >= graduation::GRADUATION_THRESHOLD,
+        curve_vault_lamports >= launch.graduation_threshold,
         LaunchrError::InsufficientGraduationFunds
     );
 
-    // Transfer 2 SOL to creator
-    msg!("Transferring {} SOL to creator...", graduation::CREATOR_REWARD_LAMPORTS as f64 / 1e9);
-    **ctx.accounts.curve_vault.try_borrow_mut_lamports()? -= graduation::CREATOR_REWARD_LAMPORTS;
-    **ctx.accounts.creator.try_borrow_mut_lamports()? += graduation::CREATOR_REWARD_LAMPORTS;
+    let (creator_reward, treasury_fee) = launch.graduation_payouts();
+
+    // The curve vault is owned by the System Program, so transfer with PDA signer
+    let curve_vault_bump = ctx.bumps.curve_vault;
+    let curve_vault_seeds: &[&[u8]] = &[
+        CURVE_VAULT_SEED,
+        launch_key.as_ref(),
+        &[curve_vault_bump],
+    ];
 
-    // Transfer 3 SOL to treasury
-    msg!("Transferring {} SOL to treasury...", graduation::TREASURY_FEE_LAMPORTS as f64 / 1e9);
-    **ctx.accounts.curve_vault.try_borrow_mut_lamports()? -= graduation::TREASURY_FEE_LAMPORTS;
-    **ctx.accounts.treasury.try_borrow_mut_lamports()? += graduation::TREASURY_FEE_LAMPORTS;
+    for (recipient, amount, label) in [
+        (ctx.accounts.creator.to_account_info(), creator_reward, "creator"),
+        (ctx.accounts.treasury.to_account_info(), treasury_fee, "treasury"),
+    ] {
+        if amount == 0 {
+            continue;
+        }
+        msg!("Transferring {} SOL to {}...", amount as f64 / 1e9, label);
+        system_program::transfer(
+            CpiContext::new_with_signer(
+                ctx.accounts.system_program.to_account_info(),
+                system_program::Transfer {
+                    from: ctx.accounts.curve_vault.to_account_info(),
+                    to: recipient,
+                },
+                &[curve_vault_seeds],
+            ),
+            amount,
+        )?;
+    }
 
-    // Remaining 80 SOL goes to LP
+    // Remainder goes to LP
     let lp_sol_amount = ctx.accounts.curve_vault.lamports();
     msg!("LP SOL amount: {} lamports ({} SOL)", lp_sol_amount, lp_sol_amount as f64 / 1e9);
 
@@ -341,6 +422,7 @@ pub fn graduate(ctx: Context<Graduate>, params: GraduateParams) -> Result<()> {
         &ctx.accounts.orbit_holders_fee_vault.key(),
         &ctx.accounts.orbit_nft_fee_vault.key(),
         &ctx.accounts.orbit_protocol_fee_vault.key(),
+        &orbit_token_program.key(),
     );
     
     msg!("Initializing Orbit vaults...");
@@ -357,7 +439,7 @@ pub fn graduate(ctx: Context<Graduate>, params: GraduateParams) -> Result<()> {
             ctx.accounts.orbit_protocol_fee_vault.to_account_info(),
             ctx.accounts.mint.to_account_info(),
             ctx.accounts.quote_mint.to_account_info(),
-            ctx.accounts.token_program.to_account_info(),
+            orbit_token_program.clone(),
             ctx.accounts.system_program.to_account_info(),
         ],
         signer_seeds,
@@ -413,17 +495,19 @@ pub fn graduate(ctx: Context<Graduate>, params: GraduateParams) -> Result<()> {
     // add_liquidity_v2 transfers FROM owner accounts TO pool vaults
     // First consolidate graduation_vault tokens into token_vault
     if ctx.accounts.graduation_vault.amount > 0 {
-        token::transfer(
+        token_interface::transfer_checked(
             CpiContext::new_with_signer(
                 ctx.accounts.token_program.to_account_info(),
-                Transfer {
+                TransferChecked {
                     from: ctx.accounts.graduation_vault.to_account_info(),
+                    mint: ctx.accounts.mint.to_account_info(),
                     to: ctx.accounts.token_vault.to_account_info(),
                     authority: ctx.accounts.launch_authority.to_account_info(),
                 },
                 signer_seeds,
             ),
             ctx.accounts.graduation_vault.amount,
+            ctx.accounts.mint.decimals,
         )?;
     }
 
@@ -452,6 +536,7 @@ pub fn graduate(ctx: Context<Graduate>, params: GraduateParams) -> Result<()> {
         &ctx.accounts.orbit_base_vault.key(),  // pool's base vault
         &ctx.accounts.orbit_quote_vault.key(), // pool's quote vault
         &ctx.accounts.orbit_position.key(),
+        &orbit_token_program.key(),
         &[ctx.accounts.orbit_bin_array.key()], // bin arrays as remaining accounts
         &bin_ids,
         &liquidity_distribution,
@@ -472,7 +557,7 @@ pub fn graduate(ctx: Context<Graduate>, params: GraduateParams) -> Result<()> {
             ctx.accounts.orbit_base_vault.to_account_info(),
             ctx.accounts.orbit_quote_vault.to_account_info(),
             ctx.accounts.orbit_position.to_account_info(),
-            ctx.accounts.token_program.to_account_info(),
+            orbit_token_program,
             ctx.accounts.orbit_bin_array.to_account_info(),
         ],
         signer_seeds,
@@ -486,6 +571,81 @@ pub fn graduate(ctx: Context<Graduate>, params: GraduateParams) -> Result<()> {
     // The LP is effectively burned - liquidity is permanent and unwithdrawable.
     msg!("LP LOCKED - position owned by program PDA (permanently unwithdrawable)");
 
+    // ========== Lock Metadata ==========
+    // Only metadata still held by the launch authority (LockAtGraduation policy)
+    let lock_metadata = !ctx.accounts.metadata.data_is_empty() && {
+        let data = ctx.accounts.metadata.try_borrow_data()?;
+        let token_metadata = mpl_token_metadata::accounts::Metadata::safe_deserialize(&data)?;
+        token_metadata.is_mutable
+            && token_metadata.update_authority == ctx.accounts.launch_authority.key()
+    };
+    if lock_metadata {
+        metadata::update_metadata_accounts_v2(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_metadata_program.to_account_info(),
+                UpdateMetadataAccountsV2 {
+                    metadata: ctx.accounts.metadata.to_account_info(),
+                    update_authority: ctx.accounts.launch_authority.to_account_info(),
+                },
+                signer_seeds,
+            ),
+            None,
+            None,
+            None,
+            Some(false),
+        )?;
+        msg!("Token metadata locked (immutable)");
+    }
+
+    // Token-2022 launches keep metadata on the mint; dropping the update authority locks it
+    let lock_mint_metadata = ctx.accounts.token_program.key() == anchor_spl::token_2022::ID && {
+        let mint_info = ctx.accounts.mint.to_account_info();
+        let data = mint_info.try_borrow_data()?;
+        let mint_state = StateWithExtensions::<MintState>::unpack(&data)?;
+        mint_state
+            .get_variable_len_extension::<TokenMetadata>()
+            .map(|token_metadata| {
+                Option::<Pubkey>::from(token_metadata.update_authority)
+                    == Some(ctx.accounts.launch_authority.key())
+            })
+            .unwrap_or(false)
+    };
+    if lock_mint_metadata {
+        token_interface::token_metadata_update_authority(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                TokenMetadataUpdateAuthority {
+                    program_id: ctx.accounts.token_program.to_account_info(),
+                    metadata: ctx.accounts.mint.to_account_info(),
+                    current_authority: ctx.accounts.launch_authority.to_account_info(),
+                    new_authority: ctx.accounts.launch_authority.to_account_info(),
+                },
+                signer_seeds,
+            ),
+            OptionalNonZeroPubkey::default(),
+        )?;
+        msg!("Mint metadata locked (update authority removed)");
+    }
+
+    // ========== Revoke Mint Authorities ==========
+    // Launches created before authorities were revoked at creation still hold them
+    let launch_authority_key = ctx.accounts.launch_authority.key();
+    for (current, authority_type, label) in [
+        (ctx.accounts.mint.mint_authority, AuthorityType::MintTokens, "Mint"),
+        (ctx.accounts.mint.freeze_authority, AuthorityType::FreezeAccount, "Freeze"),
+    ] {
+        if current == COption::Some(launch_authority_key) {
+            revoke_mint_authority(
+                ctx.accounts.token_program.to_account_info(),
+                ctx.accounts.mint.to_account_info(),
+                ctx.accounts.launch_authority.to_account_info(),
+                signer_seeds,
+                authority_type,
+            )?;
+            msg!("{} authority revoked", label);
+        }
+    }
+
     // ========== Update State ==========
 
     launch.graduate(ctx.accounts.orbit_pool.key(), clock.unix_timestamp);
@@ -500,8 +660,8 @@ pub fn graduate(ctx: Context<Graduate>, params: GraduateParams) -> Result<()> {
         token_liquidity: token_amount,
         final_price: current_price,
         active_bin_index,
-        creator_reward: graduation::CREATOR_REWARD_LAMPORTS,
-        treasury_fee: graduation::TREASURY_FEE_LAMPORTS,
+        creator_reward,
+        treasury_fee,
         timestamp: clock.unix_timestamp,
     });
 
@@ -512,8 +672,8 @@ pub fn graduate(ctx: Context<Graduate>, params: GraduateParams) -> Result<()> {
         token_amount as f64 / 1e9
     );
     msg!("Strategy: Balanced 40/40/20 across {} bins", (num_bins_per_side * 2) + 1);
-    msg!("Creator reward: {} SOL", graduation::CREATOR_REWARD_LAMPORTS as f64 / 1e9);
-    msg!("Treasury fee: {} SOL", graduation::TREASURY_FEE_LAMPORTS as f64 / 1e9);
+    msg!("Creator reward: {} SOL", creator_reward as f64 / 1e9);
+    msg!("Treasury fee: {} SOL", treasury_fee as f64 / 1e9);
     msg!("LP LOCKED - position owned by program PDA (permanent liquidity)");
 
     Ok(())
@@ -525,15 +685,15 @@ pub struct LaunchGraduated {
     pub launch: Pubkey,
     pub mint: Pubkey,
     pub orbit_pool: Pubkey,
-    /// SOL sent to LP (80 SOL)
+    /// SOL sent to LP (curve SOL minus creator reward and treasury fee)
     pub sol_liquidity: u64,
     /// Tokens sent to LP (20% of supply)
     pub token_liquidity: u64,
     pub final_price: u64,
     pub active_bin_index: i32,
-    /// SOL reward sent to creator (2 SOL)
+    /// SOL reward sent to creator (share of the threshold)
     pub creator_reward: u64,
-    /// SOL fee sent to treasury (3 SOL)
+    /// SOL fee sent to treasury (share of the threshold)
     pub treasury_fee: u64,
     pub timestamp: i64,
 }
@@ -599,6 +759,7 @@ fn build_init_vaults_instruction(
     holders_fee_vault: &Pubkey,
     nft_fee_vault: &Pubkey,
     protocol_fee_vault: &Pubkey,
+    token_program: &Pubkey,
 ) -> Instruction {
     let mut data = Vec::new();
     data.extend_from_slice(&INIT_POOL_VAULTS_DISCRIMINATOR);
@@ -616,7 +777,7 @@ fn build_init_vaults_instruction(
             AccountMeta::new(*protocol_fee_vault, false),
             AccountMeta::new_readonly(*base_mint, false),
             AccountMeta::new_readonly(*quote_mint, false),
-            AccountMeta::new_readonly(anchor_spl::token::ID, false),
+            AccountMeta::new_readonly(*token_program, false),
             AccountMeta::new_readonly(anchor_lang::solana_program::system_program::ID, false),
         ],
         data,
@@ -688,6 +849,7 @@ fn build_add_liquidity_v2_instruction(
     base_vault: &Pubkey,   // Pool's base vault (destination)
     quote_vault: &Pubkey,  // Pool's quote vault (destination)
     position: &Pubkey,
+    token_program: &Pubkey,
     bin_arrays: &[Pubkey], // Remaining accounts for bin arrays
     bin_ids: &[i32],
     distribution: &[u64],
@@ -716,7 +878,7 @@ fn build_add_liquidity_v2_instruction(
         AccountMeta::new(*base_vault, false),
         AccountMeta::new(*quote_vault, false),
         AccountMeta::new(*position, false),
-        AccountMeta::new_readonly(anchor_spl::token::ID, false),
+        AccountMeta::new_readonly(*token_program, false),
     ];
 
     // Add bin arrays as remaining accounts
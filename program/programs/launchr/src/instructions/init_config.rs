@@ -5,6 +5,7 @@
 use anchor_lang::prelude::*;
 use crate::seeds::*;
 use crate::state::*;
+use crate::instructions::multisig::authorize_admin_action;
 
 /// Initialize the global Launchr configuration
 /// 
@@ -54,9 +55,13 @@ pub fn init_config(ctx: Context<InitConfig>, params: InitConfigParams) -> Result
     let config = &mut ctx.accounts.config;
     
     // Validate parameters
-    require!(params.protocol_fee_bps <= 1000, crate::math::LaunchrError::InvalidConfig); // Max 10%
+    require!(params.protocol_fee_bps <= limits::MAX_PROTOCOL_FEE_BPS, crate::math::LaunchrError::InvalidConfig);
     require!(params.graduation_threshold > 0, crate::math::LaunchrError::InvalidConfig);
-    require!(params.default_bin_step_bps > 0 && params.default_bin_step_bps <= 500, crate::math::LaunchrError::InvalidConfig);
+    require!(
+        (limits::MIN_BIN_STEP_BPS..=limits::MAX_BIN_STEP_BPS).contains(&params.default_bin_step_bps),
+        crate::math::LaunchrError::InvalidConfig
+    );
+    require!(params.default_base_fee_bps <= limits::MAX_BASE_FEE_BPS, crate::math::LaunchrError::InvalidConfig);
     
     config.init(
         ctx.accounts.admin.key(),
@@ -80,21 +85,41 @@ pub fn init_config(ctx: Context<InitConfig>, params: InitConfigParams) -> Result
 /// Update configuration parameters
 #[derive(Accounts)]
 pub struct UpdateConfig<'info> {
-    /// Admin authority
-    pub admin: Signer<'info>,
+    /// Admin or holder of the roles required by the update
+    pub authority: Signer<'info>,
     
     /// Global config account
     #[account(
         mut,
         seeds = [CONFIG_SEED],
-        bump = config.bump,
-        constraint = config.admin == admin.key() @ crate::math::LaunchrError::Unauthorized
+        bump = config.bump
     )]
     pub config: Account<'info, Config>,
+    
+    /// Delegated roles (required unless signed by the admin)
+    #[account(
+        seeds = [ROLES_SEED, config.key().as_ref()],
+        bump = roles.bump
+    )]
+    pub roles: Option<Account<'info, Roles>>,
+    
+    /// New quote mint (required when changing the quote mint)
+    pub new_quote_mint: Option<Account<'info, anchor_spl::token::Mint>>,
+    
+    /// Admin multisig (required in multisig mode unless roles cover the update)
+    #[account(
+        seeds = [MULTISIG_SEED, config.key().as_ref()],
+        bump = multisig.bump
+    )]
+    pub multisig: Option<Account<'info, Multisig>>,
+    
+    /// Approved proposal for this update (required alongside `multisig`)
+    #[account(mut)]
+    pub proposal: Option<Account<'info, Proposal>>,
 }
 
 /// Parameters for updating config
-#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default, PartialEq, Eq)]
 pub struct UpdateConfigParams {
     /// New fee authority (optional)
     pub new_fee_authority: Option<Pubkey>,
@@ -106,11 +131,240 @@ pub struct UpdateConfigParams {
     pub launches_paused: Option<bool>,
     /// Pause/unpause trading
     pub trading_paused: Option<bool>,
+    /// New refund window in seconds for future launches (0 = disabled)
+    pub new_refund_window_secs: Option<u32>,
+    /// New default bin step for Orbit pools (optional)
+    pub new_default_bin_step_bps: Option<u16>,
+    /// New default base fee for Orbit pools (optional)
+    pub new_default_base_fee_bps: Option<u16>,
+    /// New Orbit Finance program ID (optional)
+    pub new_orbit_program_id: Option<Pubkey>,
+    /// New quote mint for Orbit pools (optional, must match `new_quote_mint` account)
+    pub new_quote_mint: Option<Pubkey>,
+    /// New config timelock delay in seconds (optional)
+    pub new_config_timelock_secs: Option<u32>,
+    /// New graduation creator reward for future launches (optional, BPS of threshold)
+    pub new_graduation_creator_reward_bps: Option<u16>,
+    /// New graduation treasury fee for future launches (optional, BPS of threshold)
+    pub new_graduation_treasury_fee_bps: Option<u16>,
+    /// New token metadata policy for future launches (optional)
+    pub new_metadata_policy: Option<MetadataPolicy>,
+    /// New maximum trading start delay in seconds for future launches (optional)
+    pub new_max_trading_start_delay_secs: Option<u32>,
+    /// New anti-sniper window in seconds for future launches (optional, 0 = disabled)
+    pub new_sniper_window_secs: Option<u32>,
+    /// New anti-sniper per-transaction buy cap for future launches (optional, BPS of threshold)
+    pub new_sniper_max_buy_bps: Option<u16>,
+    /// New anti-sniper per-wallet token cap for future launches (optional, BPS of supply)
+    pub new_sniper_max_wallet_bps: Option<u16>,
+}
+
+impl UpdateConfigParams {
+    /// Maximum serialized size (every field set)
+    pub const MAX_SIZE: usize =
+        (1 + 32) +  // new_fee_authority
+        (1 + 2) +   // new_protocol_fee_bps
+        (1 + 8) +   // new_graduation_threshold
+        (1 + 1) +   // launches_paused
+        (1 + 1) +   // trading_paused
+        (1 + 4) +   // new_refund_window_secs
+        (1 + 2) +   // new_default_bin_step_bps
+        (1 + 2) +   // new_default_base_fee_bps
+        (1 + 32) +  // new_orbit_program_id
+        (1 + 32) +  // new_quote_mint
+        (1 + 4) +   // new_config_timelock_secs
+        (1 + 2) +   // new_graduation_creator_reward_bps
+        (1 + 2) +   // new_graduation_treasury_fee_bps
+        (1 + 1) +   // new_metadata_policy
+        (1 + 4) +   // new_max_trading_start_delay_secs
+        (1 + 4) +   // new_sniper_window_secs
+        (1 + 2) +   // new_sniper_max_buy_bps
+        (1 + 2);    // new_sniper_max_wallet_bps
+    
+    /// Check if any field other than the pause flags is set
+    ///
+    /// Pause flags are exempt from the config timelock so emergency pauses stay instant.
+    pub fn has_timelocked_changes(&self) -> bool {
+        self.new_fee_authority.is_some()
+            || self.new_protocol_fee_bps.is_some()
+            || self.new_graduation_threshold.is_some()
+            || self.new_refund_window_secs.is_some()
+            || self.new_default_bin_step_bps.is_some()
+            || self.new_default_base_fee_bps.is_some()
+            || self.new_orbit_program_id.is_some()
+            || self.new_quote_mint.is_some()
+            || self.new_config_timelock_secs.is_some()
+            || self.new_graduation_creator_reward_bps.is_some()
+            || self.new_graduation_treasury_fee_bps.is_some()
+            || self.new_metadata_policy.is_some()
+            || self.new_max_trading_start_delay_secs.is_some()
+            || self.new_sniper_window_secs.is_some()
+            || self.new_sniper_max_buy_bps.is_some()
+            || self.new_sniper_max_wallet_bps.is_some()
+    }
+    
+    /// Check if any pause flag is set
+    pub fn has_pause_changes(&self) -> bool {
+        self.launches_paused.is_some() || self.trading_paused.is_some()
+    }
+    
+    /// Check if any fee rate is set
+    ///
+    /// The fee authority is not a fee rate: redirecting the treasury stays admin-only.
+    pub fn has_fee_changes(&self) -> bool {
+        self.new_protocol_fee_bps.is_some()
+            || self.new_default_base_fee_bps.is_some()
+            || self.new_graduation_creator_reward_bps.is_some()
+            || self.new_graduation_treasury_fee_bps.is_some()
+    }
+    
+    /// Check if any protocol parameter (thresholds, windows, Orbit settings) is set
+    pub fn has_parameter_changes(&self) -> bool {
+        self.new_graduation_threshold.is_some()
+            || self.new_refund_window_secs.is_some()
+            || self.new_default_bin_step_bps.is_some()
+            || self.new_orbit_program_id.is_some()
+            || self.new_quote_mint.is_some()
+            || self.new_metadata_policy.is_some()
+            || self.new_max_trading_start_delay_secs.is_some()
+            || self.new_sniper_window_secs.is_some()
+            || self.new_sniper_max_buy_bps.is_some()
+            || self.new_sniper_max_wallet_bps.is_some()
+    }
+    
+    /// Check if `key`'s roles alone authorize this update
+    ///
+    /// Pause flags need the pauser, fee rates the fee manager and protocol
+    /// parameters the parameter manager. In multisig mode only the pause
+    /// flags can skip a proposal. The fee authority and the timelock are
+    /// never covered by a role.
+    pub fn covered_by_roles(&self, config: &Config, roles: Option<&Roles>, key: &Pubkey) -> bool {
+        let delegated = !config.multisig_enabled;
+        (!self.has_pause_changes() || Roles::has_role(config, roles, key, Role::Pauser))
+            && (!self.has_fee_changes() || (delegated && Roles::has_role(config, roles, key, Role::FeeManager)))
+            && (!self.has_parameter_changes()
+                || (delegated && Roles::has_role(config, roles, key, Role::ParameterManager)))
+            && self.new_fee_authority.is_none()
+            && self.new_config_timelock_secs.is_none()
+    }
 }
 
 /// Update config parameters
 pub fn update_config(ctx: Context<UpdateConfig>, params: UpdateConfigParams) -> Result<()> {
-    let config = &mut ctx.accounts.config;
+    // With a timelock configured, only pause flags apply immediately
+    require!(
+        ctx.accounts.config.config_timelock_secs == 0 || !params.has_timelocked_changes(),
+        crate::math::LaunchrError::TimelockRequired
+    );
+    
+    let updated_by = ctx.accounts.authority.key();
+    authorize_config_update(
+        &ctx.accounts.config,
+        ctx.accounts.roles.as_deref(),
+        &updated_by,
+        &params,
+        ctx.accounts.multisig.as_ref(),
+        ctx.accounts.proposal.as_mut(),
+        AdminAction::UpdateConfig(params.clone()),
+    )?;
+    
+    check_quote_mint_account(&params, ctx.accounts.new_quote_mint.as_ref())?;
+    
+    apply_config_update(&mut ctx.accounts.config, &params, updated_by)
+}
+
+/// Ensure `key` may apply the update
+///
+/// Anything the signer's roles don't cover (see `covered_by_roles`) falls
+/// back to admin authorization, which in multisig mode means an approved
+/// proposal for `action`.
+pub(crate) fn authorize_config_update(
+    config: &Config,
+    roles: Option<&Roles>,
+    key: &Pubkey,
+    params: &UpdateConfigParams,
+    multisig: Option<&Account<Multisig>>,
+    proposal: Option<&mut Account<Proposal>>,
+    action: AdminAction,
+) -> Result<()> {
+    if params.covered_by_roles(config, roles, key) {
+        return Ok(());
+    }
+    authorize_admin_action(config, &config.admin, key, multisig, proposal, action)
+}
+
+/// Ensure a quote mint change comes with the matching mint account
+pub(crate) fn check_quote_mint_account(
+    params: &UpdateConfigParams,
+    new_quote_mint: Option<&Account<anchor_spl::token::Mint>>,
+) -> Result<()> {
+    if let Some(quote_mint) = params.new_quote_mint {
+        let mint_account = new_quote_mint.ok_or(error!(crate::math::LaunchrError::InvalidConfig))?;
+        require!(mint_account.key() == quote_mint, crate::math::LaunchrError::InvalidConfig);
+    }
+    Ok(())
+}
+
+/// Validate config update values against the protocol limits
+pub(crate) fn validate_config_update(params: &UpdateConfigParams) -> Result<()> {
+    if let Some(protocol_fee_bps) = params.new_protocol_fee_bps {
+        require!(protocol_fee_bps <= limits::MAX_PROTOCOL_FEE_BPS, crate::math::LaunchrError::InvalidConfig);
+    }
+    
+    if let Some(graduation_threshold) = params.new_graduation_threshold {
+        require!(graduation_threshold > 0, crate::math::LaunchrError::InvalidConfig);
+    }
+    
+    if let Some(bin_step_bps) = params.new_default_bin_step_bps {
+        require!(
+            (limits::MIN_BIN_STEP_BPS..=limits::MAX_BIN_STEP_BPS).contains(&bin_step_bps),
+            crate::math::LaunchrError::InvalidConfig
+        );
+    }
+    
+    if let Some(base_fee_bps) = params.new_default_base_fee_bps {
+        require!(base_fee_bps <= limits::MAX_BASE_FEE_BPS, crate::math::LaunchrError::InvalidConfig);
+    }
+    
+    if let Some(orbit_program_id) = params.new_orbit_program_id {
+        require!(orbit_program_id != Pubkey::default(), crate::math::LaunchrError::InvalidConfig);
+    }
+    
+    if let Some(timelock_secs) = params.new_config_timelock_secs {
+        require!(timelock_secs <= limits::MAX_CONFIG_TIMELOCK_SECS, crate::math::LaunchrError::InvalidConfig);
+    }
+    
+    if let Some(reward_bps) = params.new_graduation_creator_reward_bps {
+        require!(reward_bps <= limits::MAX_GRADUATION_CREATOR_REWARD_BPS, crate::math::LaunchrError::InvalidConfig);
+    }
+    
+    if let Some(fee_bps) = params.new_graduation_treasury_fee_bps {
+        require!(fee_bps <= limits::MAX_GRADUATION_TREASURY_FEE_BPS, crate::math::LaunchrError::InvalidConfig);
+    }
+    
+    if let Some(delay_secs) = params.new_max_trading_start_delay_secs {
+        require!(delay_secs <= limits::MAX_TRADING_START_DELAY_SECS, crate::math::LaunchrError::InvalidConfig);
+    }
+    
+    if let Some(window_secs) = params.new_sniper_window_secs {
+        require!(window_secs <= limits::MAX_SNIPER_WINDOW_SECS, crate::math::LaunchrError::InvalidConfig);
+    }
+    
+    for cap_bps in [params.new_sniper_max_buy_bps, params.new_sniper_max_wallet_bps].into_iter().flatten() {
+        require!(cap_bps <= limits::MAX_SNIPER_CAP_BPS, crate::math::LaunchrError::InvalidConfig);
+    }
+    
+    Ok(())
+}
+
+/// Validate and apply a config update, emitting `ConfigUpdated`
+pub(crate) fn apply_config_update(
+    config: &mut Config,
+    params: &UpdateConfigParams,
+    updated_by: Pubkey,
+) -> Result<()> {
+    validate_config_update(params)?;
+    let old = config.settings();
     
     if let Some(fee_authority) = params.new_fee_authority {
         config.fee_authority = fee_authority;
@@ -118,13 +372,11 @@ pub fn update_config(ctx: Context<UpdateConfig>, params: UpdateConfigParams) ->
     }
     
     if let Some(protocol_fee_bps) = params.new_protocol_fee_bps {
-        require!(protocol_fee_bps <= 1000, crate::math::LaunchrError::InvalidConfig);
         config.protocol_fee_bps = protocol_fee_bps;
         msg!("Updated protocol fee: {} bps", protocol_fee_bps);
     }
     
     if let Some(graduation_threshold) = params.new_graduation_threshold {
-        require!(graduation_threshold > 0, crate::math::LaunchrError::InvalidConfig);
         config.graduation_threshold = graduation_threshold;
         msg!("Updated graduation threshold: {} lamports", graduation_threshold);
     }
@@ -139,36 +391,268 @@ pub fn update_config(ctx: Context<UpdateConfig>, params: UpdateConfigParams) ->
         msg!("Trading paused: {}", paused);
     }
     
+    if let Some(refund_window_secs) = params.new_refund_window_secs {
+        config.refund_window_secs = refund_window_secs;
+        msg!("Updated refund window: {} seconds", refund_window_secs);
+    }
+    
+    if let Some(bin_step_bps) = params.new_default_bin_step_bps {
+        config.default_bin_step_bps = bin_step_bps;
+        msg!("Updated default bin step: {} bps", bin_step_bps);
+    }
+    
+    if let Some(base_fee_bps) = params.new_default_base_fee_bps {
+        config.default_base_fee_bps = base_fee_bps;
+        msg!("Updated default base fee: {} bps", base_fee_bps);
+    }
+    
+    if let Some(orbit_program_id) = params.new_orbit_program_id {
+        config.orbit_program_id = orbit_program_id;
+        msg!("Updated Orbit program: {}", orbit_program_id);
+    }
+    
+    if let Some(quote_mint) = params.new_quote_mint {
+        config.quote_mint = quote_mint;
+        msg!("Updated quote mint: {}", quote_mint);
+    }
+    
+    if let Some(timelock_secs) = params.new_config_timelock_secs {
+        config.config_timelock_secs = timelock_secs;
+        msg!("Updated config timelock: {} seconds", timelock_secs);
+    }
+    
+    if params.new_graduation_creator_reward_bps.is_some() || params.new_graduation_treasury_fee_bps.is_some() {
+        // Pin the payout a pre-upgrade config was implicitly using before changing the other one
+        (config.graduation_creator_reward_bps, config.graduation_treasury_fee_bps) = config.graduation_payout_bps();
+        config.graduation_payouts_set = true;
+    }
+    
+    if let Some(reward_bps) = params.new_graduation_creator_reward_bps {
+        config.graduation_creator_reward_bps = reward_bps;
+        msg!("Updated graduation creator reward: {} bps", reward_bps);
+    }
+    
+    if let Some(fee_bps) = params.new_graduation_treasury_fee_bps {
+        config.graduation_treasury_fee_bps = fee_bps;
+        msg!("Updated graduation treasury fee: {} bps", fee_bps);
+    }
+    
+    if let Some(metadata_policy) = params.new_metadata_policy {
+        config.metadata_policy = metadata_policy;
+        msg!("Updated metadata policy: {:?}", metadata_policy);
+    }
+    
+    if let Some(delay_secs) = params.new_max_trading_start_delay_secs {
+        config.max_trading_start_delay_secs = delay_secs;
+        config.trading_start_delay_set = true;
+        msg!("Updated max trading start delay: {} seconds", delay_secs);
+    }
+    
+    if let Some(window_secs) = params.new_sniper_window_secs {
+        config.sniper_window_secs = window_secs;
+        msg!("Updated anti-sniper window: {} seconds", window_secs);
+    }
+    
+    if let Some(cap_bps) = params.new_sniper_max_buy_bps {
+        config.sniper_max_buy_bps = cap_bps;
+        msg!("Updated anti-sniper buy cap: {} bps", cap_bps);
+    }
+    
+    if let Some(cap_bps) = params.new_sniper_max_wallet_bps {
+        config.sniper_max_wallet_bps = cap_bps;
+        msg!("Updated anti-sniper wallet cap: {} bps", cap_bps);
+    }
+    
+    let new = config.settings();
+    if new != old {
+        emit!(ConfigUpdated {
+            updated_by,
+            old,
+            new,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+    }
+    
     Ok(())
 }
 
-/// Transfer admin authority
+/// Event emitted whenever config settings change
+#[event]
+pub struct ConfigUpdated {
+    pub updated_by: Pubkey,
+    /// Settings before the change
+    pub old: ConfigSettings,
+    /// Settings after the change
+    pub new: ConfigSettings,
+    pub timestamp: i64,
+}
+
+/// Propose a new admin (step 1 of 2)
 #[derive(Accounts)]
-pub struct TransferAdmin<'info> {
-    /// Current admin
-    pub admin: Signer<'info>,
+pub struct ProposeAdmin<'info> {
+    /// Current admin, or a multisig signer executing an approved proposal
+    pub authority: Signer<'info>,
     
-    /// New admin
-    /// CHECK: Just storing the pubkey
+    /// Proposed admin
+    /// CHECK: Just storing the pubkey - must sign accept_admin to take over
     pub new_admin: UncheckedAccount<'info>,
     
+    /// Global config
+    #[account(
+        mut,
+        seeds = [CONFIG_SEED],
+        bump = config.bump
+    )]
+    pub config: Account<'info, Config>,
+    
+    /// Admin multisig (required in multisig mode)
+    #[account(
+        seeds = [MULTISIG_SEED, config.key().as_ref()],
+        bump = multisig.bump
+    )]
+    pub multisig: Option<Account<'info, Multisig>>,
+    
+    /// Approved proposal (required in multisig mode)
+    #[account(mut)]
+    pub proposal: Option<Account<'info, Proposal>>,
+}
+
+/// Propose a new admin authority
+pub fn propose_admin(ctx: Context<ProposeAdmin>) -> Result<()> {
+    let new_admin = ctx.accounts.new_admin.key();
+    
+    require!(new_admin != Pubkey::default(), crate::math::LaunchrError::InvalidConfig);
+    
+    authorize_admin_action(
+        &ctx.accounts.config,
+        &ctx.accounts.config.admin,
+        &ctx.accounts.authority.key(),
+        ctx.accounts.multisig.as_ref(),
+        ctx.accounts.proposal.as_mut(),
+        AdminAction::ProposeAdmin { new_admin },
+    )?;
+    
+    let config = &mut ctx.accounts.config;
+    config.pending_admin = new_admin;
+    msg!("Proposed admin transfer from {} to {}", config.admin, new_admin);
+    
+    emit!(AdminTransferProposed {
+        admin: config.admin,
+        pending_admin: new_admin,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    
+    Ok(())
+}
+
+/// Accept a pending admin transfer (step 2 of 2)
+#[derive(Accounts)]
+pub struct AcceptAdmin<'info> {
+    /// Pending admin
+    pub new_admin: Signer<'info>,
+    
     /// Global config
     #[account(
         mut,
         seeds = [CONFIG_SEED],
         bump = config.bump,
-        constraint = config.admin == admin.key() @ crate::math::LaunchrError::Unauthorized
+        constraint = config.has_pending_admin() @ crate::math::LaunchrError::NoPendingAdmin,
+        constraint = config.pending_admin == new_admin.key() @ crate::math::LaunchrError::Unauthorized
     )]
     pub config: Account<'info, Config>,
 }
 
-/// Transfer admin authority to a new account
-pub fn transfer_admin(ctx: Context<TransferAdmin>) -> Result<()> {
+/// Accept a pending admin transfer
+pub fn accept_admin(ctx: Context<AcceptAdmin>) -> Result<()> {
     let config = &mut ctx.accounts.config;
-    let new_admin = ctx.accounts.new_admin.key();
+    let previous_admin = config.admin;
+    
+    config.admin = config.pending_admin;
+    config.pending_admin = Pubkey::default();
+    msg!("Admin transferred from {} to {}", previous_admin, config.admin);
     
-    msg!("Transferring admin from {} to {}", config.admin, new_admin);
-    config.admin = new_admin;
+    emit!(AdminTransferAccepted {
+        previous_admin,
+        new_admin: config.admin,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
     
     Ok(())
 }
+
+/// Cancel a pending admin transfer
+#[derive(Accounts)]
+pub struct CancelAdminTransfer<'info> {
+    /// Current admin, or a multisig signer executing an approved proposal
+    pub authority: Signer<'info>,
+    
+    /// Global config
+    #[account(
+        mut,
+        seeds = [CONFIG_SEED],
+        bump = config.bump,
+        constraint = config.has_pending_admin() @ crate::math::LaunchrError::NoPendingAdmin
+    )]
+    pub config: Account<'info, Config>,
+    
+    /// Admin multisig (required in multisig mode)
+    #[account(
+        seeds = [MULTISIG_SEED, config.key().as_ref()],
+        bump = multisig.bump
+    )]
+    pub multisig: Option<Account<'info, Multisig>>,
+    
+    /// Approved proposal (required in multisig mode)
+    #[account(mut)]
+    pub proposal: Option<Account<'info, Proposal>>,
+}
+
+/// Cancel a pending admin transfer
+pub fn cancel_admin_transfer(ctx: Context<CancelAdminTransfer>) -> Result<()> {
+    authorize_admin_action(
+        &ctx.accounts.config,
+        &ctx.accounts.config.admin,
+        &ctx.accounts.authority.key(),
+        ctx.accounts.multisig.as_ref(),
+        ctx.accounts.proposal.as_mut(),
+        AdminAction::CancelAdminTransfer,
+    )?;
+    
+    let config = &mut ctx.accounts.config;
+    let cancelled_admin = config.pending_admin;
+    
+    config.pending_admin = Pubkey::default();
+    msg!("Cancelled admin transfer to {}", cancelled_admin);
+    
+    emit!(AdminTransferCancelled {
+        admin: config.admin,
+        cancelled_admin,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    
+    Ok(())
+}
+
+/// Event emitted when a new admin is proposed
+#[event]
+pub struct AdminTransferProposed {
+    pub admin: Pubkey,
+    pub pending_admin: Pubkey,
+    pub timestamp: i64,
+}
+
+/// Event emitted when the pending admin accepts
+#[event]
+pub struct AdminTransferAccepted {
+    pub previous_admin: Pubkey,
+    pub new_admin: Pubkey,
+    pub timestamp: i64,
+}
+
+/// Event emitted when a pending admin transfer is cancelled
+#[event]
+pub struct AdminTransferCancelled {
+    pub admin: Pubkey,
+    pub cancelled_admin: Pubkey,
+    pub timestamp: i64,
+}
@@ -8,9 +8,27 @@ pub mod create_launch;
 pub mod buy;
 pub mod sell;
 pub mod graduate;
+pub mod withdraw_fees;
+pub mod cancel_launch;
+pub mod refund;
+pub mod config_timelock;
+pub mod roles;
+pub mod multisig;
+pub mod moderation;
+pub mod trading_start;
+pub mod position;
 
 pub use init_config::*;
 pub use create_launch::*;
 pub use buy::*;
 pub use sell::*;
 pub use graduate::*;
+pub use withdraw_fees::*;
+pub use cancel_launch::*;
+pub use refund::*;
+pub use config_timelock::*;
+pub use roles::*;
+pub use multisig::*;
+pub use moderation::*;
+pub use trading_start::*;
+pub use position::*;
@@ -0,0 +1,116 @@
+//! Launchr - Launch Moderation
+//!
+//! Pause, delist or hide a single launch without halting the whole platform.
+
+use anchor_lang::prelude::*;
+use crate::seeds::*;
+use crate::state::*;
+use crate::math::LaunchrError;
+use crate::instructions::multisig::authorize_admin_action;
+
+/// Set moderation flags on a launch
+#[derive(Accounts)]
+pub struct SetLaunchFlags<'info> {
+    /// Moderator, admin, or a multisig signer executing an approved proposal
+    pub authority: Signer<'info>,
+
+    /// Global config
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump = config.bump
+    )]
+    pub config: Account<'info, Config>,
+
+    /// Delegated roles (required unless signed by the admin)
+    #[account(
+        seeds = [ROLES_SEED, config.key().as_ref()],
+        bump = roles.bump
+    )]
+    pub roles: Option<Account<'info, Roles>>,
+
+    /// Launch to moderate
+    #[account(
+        mut,
+        seeds = [LAUNCH_SEED, launch.mint.as_ref()],
+        bump = launch.bump
+    )]
+    pub launch: Box<Account<'info, Launch>>,
+
+    /// Admin multisig (required in multisig mode unless signed by the moderator)
+    #[account(
+        seeds = [MULTISIG_SEED, config.key().as_ref()],
+        bump = multisig.bump
+    )]
+    pub multisig: Option<Account<'info, Multisig>>,
+
+    /// Approved proposal (required alongside `multisig`)
+    #[account(mut)]
+    pub proposal: Option<Account<'info, Proposal>>,
+}
+
+/// Parameters for setting launch flags
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct SetLaunchFlagsParams {
+    /// New flags (replaces the current value, see `launch_flags`)
+    pub flags: u8,
+}
+
+/// Replace a launch's moderation flags
+pub fn set_launch_flags(ctx: Context<SetLaunchFlags>, params: SetLaunchFlagsParams) -> Result<()> {
+    require!(params.flags & !launch_flags::ALL == 0, LaunchrError::InvalidConfig);
+
+    let authority = ctx.accounts.authority.key();
+    let launch_key = ctx.accounts.launch.key();
+    let is_moderator = Roles::has_role(&ctx.accounts.config, ctx.accounts.roles.as_deref(), &authority, Role::Moderator);
+    if !(is_moderator && moderator_may_set(&ctx.accounts.config, ctx.accounts.launch.flags, params.flags)) {
+        authorize_admin_action(
+            &ctx.accounts.config,
+            &ctx.accounts.config.admin,
+            &authority,
+            ctx.accounts.multisig.as_ref(),
+            ctx.accounts.proposal.as_mut(),
+            AdminAction::SetLaunchFlags { launch: launch_key, flags: params.flags },
+        )?;
+    }
+
+    let launch = &mut ctx.accounts.launch;
+    let old_flags = launch.flags;
+    launch.flags = params.flags;
+
+    emit!(LaunchFlagsUpdated {
+        launch: launch_key,
+        mint: launch.mint,
+        old_flags,
+        new_flags: params.flags,
+        updated_by: authority,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    msg!("Launch flags for {} ({}): {:#04x} -> {:#04x}",
+        launch.name_str(),
+        launch.symbol_str(),
+        old_flags,
+        params.flags
+    );
+
+    Ok(())
+}
+
+/// Check if the moderator role alone may change `old_flags` to `new_flags`
+///
+/// In multisig mode the moderator can only toggle the trading pause, like
+/// the protocol-wide pause flags; delisting and hiding need a proposal.
+pub(crate) fn moderator_may_set(config: &Config, old_flags: u8, new_flags: u8) -> bool {
+    !config.multisig_enabled || (old_flags ^ new_flags) & !launch_flags::TRADING_PAUSED == 0
+}
+
+/// Event emitted when a launch's moderation flags change
+#[event]
+pub struct LaunchFlagsUpdated {
+    pub launch: Pubkey,
+    pub mint: Pubkey,
+    pub old_flags: u8,
+    pub new_flags: u8,
+    pub updated_by: Pubkey,
+    pub timestamp: i64,
+}
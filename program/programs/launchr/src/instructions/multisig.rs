@@ -0,0 +1,439 @@
+//! Launchr - Admin Multisig
+//!
+//! Optional M-of-N control over admin actions. Signers create a proposal
+//! naming the exact action, collect approvals, and a signer then calls the
+//! admin instruction itself with the proposal attached to execute it.
+
+use anchor_lang::prelude::*;
+use crate::seeds::*;
+use crate::state::*;
+use crate::math::LaunchrError;
+
+/// Check that `signer` may perform an admin action
+///
+/// Without multisig mode the signer must be `direct_authority` (the admin, or
+/// the fee authority for withdrawals). In multisig mode the signer must be a
+/// multisig member presenting an approved proposal for exactly `action`; the
+/// proposal is marked executed so it can't be replayed.
+pub(crate) fn authorize_admin_action(
+    config: &Config,
+    direct_authority: &Pubkey,
+    signer: &Pubkey,
+    multisig: Option<&Account<Multisig>>,
+    proposal: Option<&mut Account<Proposal>>,
+    action: AdminAction,
+) -> Result<()> {
+    if !config.multisig_enabled {
+        require!(direct_authority == signer, LaunchrError::Unauthorized);
+        return Ok(());
+    }
+
+    let multisig = multisig.ok_or(error!(LaunchrError::MultisigRequired))?;
+    let proposal = proposal.ok_or(error!(LaunchrError::MultisigRequired))?;
+    let clock = Clock::get()?;
+
+    require!(proposal.multisig == multisig.key(), LaunchrError::ProposalActionMismatch);
+    require!(multisig.is_signer(signer), LaunchrError::NotMultisigSigner);
+    require!(proposal.is_open(multisig, clock.unix_timestamp), LaunchrError::ProposalNotOpen);
+    require!(
+        proposal.is_approved(multisig, clock.unix_timestamp),
+        LaunchrError::ProposalNotApproved
+    );
+    require!(proposal.action == action, LaunchrError::ProposalActionMismatch);
+
+    proposal.executed = true;
+
+    emit!(ProposalExecuted {
+        multisig: multisig.key(),
+        proposal: proposal.key(),
+        index: proposal.index,
+        executor: *signer,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Executed multisig proposal #{}", proposal.index);
+
+    Ok(())
+}
+
+/// Configure the admin multisig
+#[derive(Accounts)]
+pub struct ConfigureMultisig<'info> {
+    /// Admin, or a multisig signer executing an approved proposal
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    /// Global config
+    #[account(
+        mut,
+        seeds = [CONFIG_SEED],
+        bump = config.bump
+    )]
+    pub config: Account<'info, Config>,
+
+    /// Multisig (created on first configuration)
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = Multisig::LEN,
+        seeds = [MULTISIG_SEED, config.key().as_ref()],
+        bump
+    )]
+    pub multisig: Account<'info, Multisig>,
+
+    /// Approved proposal (required in multisig mode)
+    #[account(mut)]
+    pub proposal: Option<Account<'info, Proposal>>,
+
+    /// System program
+    pub system_program: Program<'info, System>,
+}
+
+/// Parameters for configuring the multisig
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, Debug)]
+pub struct ConfigureMultisigParams {
+    /// Signer keys (1..=MAX_MULTISIG_SIGNERS, no duplicates)
+    pub signers: Vec<Pubkey>,
+    /// Approvals required (1..=signers.len())
+    pub threshold: u8,
+    /// Seconds a proposal stays open
+    pub proposal_lifetime_secs: u32,
+    /// Whether admin actions must go through the multisig
+    pub enabled: bool,
+}
+
+impl ConfigureMultisigParams {
+    /// Maximum serialized size
+    pub const MAX_SIZE: usize =
+        4 + 32 * MAX_MULTISIG_SIGNERS + // signers
+        1 +     // threshold
+        4 +     // proposal_lifetime_secs
+        1;      // enabled
+
+    /// Check signer set and threshold are well-formed
+    pub fn is_valid(&self) -> bool {
+        let count = self.signers.len();
+        if count == 0 || count > MAX_MULTISIG_SIGNERS {
+            return false;
+        }
+        if self.threshold == 0 || self.threshold as usize > count {
+            return false;
+        }
+        if self.proposal_lifetime_secs == 0 {
+            return false;
+        }
+        self.signers.iter().enumerate().all(|(i, signer)| {
+            *signer != Pubkey::default() && !self.signers[..i].contains(signer)
+        })
+    }
+}
+
+/// Set the multisig signers, threshold and whether multisig mode is enabled
+///
+/// Changing the signer set invalidates every open proposal.
+pub fn configure_multisig(ctx: Context<ConfigureMultisig>, params: ConfigureMultisigParams) -> Result<()> {
+    require!(params.is_valid(), LaunchrError::InvalidConfig);
+
+    authorize_admin_action(
+        &ctx.accounts.config,
+        &ctx.accounts.config.admin,
+        &ctx.accounts.authority.key(),
+        Some(&ctx.accounts.multisig),
+        ctx.accounts.proposal.as_mut(),
+        AdminAction::ConfigureMultisig(params.clone()),
+    )?;
+
+    let multisig = &mut ctx.accounts.multisig;
+    multisig.config = ctx.accounts.config.key();
+    multisig.bump = ctx.bumps.multisig;
+    multisig.set_signers(&params.signers, params.threshold, params.proposal_lifetime_secs);
+
+    ctx.accounts.config.multisig_enabled = params.enabled;
+
+    emit!(MultisigConfigured {
+        multisig: multisig.key(),
+        signers: params.signers,
+        threshold: params.threshold,
+        proposal_lifetime_secs: params.proposal_lifetime_secs,
+        enabled: params.enabled,
+        signer_set_version: multisig.signer_set_version,
+        configured_by: ctx.accounts.authority.key(),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    msg!("Multisig configured: {} of {} (enabled: {})",
+        params.threshold,
+        multisig.signer_count,
+        params.enabled
+    );
+
+    Ok(())
+}
+
+/// Create a proposal for an admin action
+#[derive(Accounts)]
+pub struct CreateProposal<'info> {
+    /// Multisig signer creating the proposal
+    #[account(mut)]
+    pub proposer: Signer<'info>,
+
+    /// Global config
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump = config.bump
+    )]
+    pub config: Account<'info, Config>,
+
+    /// Multisig
+    #[account(
+        mut,
+        seeds = [MULTISIG_SEED, config.key().as_ref()],
+        bump = multisig.bump,
+        constraint = multisig.is_signer(&proposer.key()) @ LaunchrError::NotMultisigSigner
+    )]
+    pub multisig: Account<'info, Multisig>,
+
+    /// New proposal
+    #[account(
+        init,
+        payer = proposer,
+        space = Proposal::LEN,
+        seeds = [PROPOSAL_SEED, multisig.key().as_ref(), &multisig.proposal_count.to_le_bytes()],
+        bump
+    )]
+    pub proposal: Account<'info, Proposal>,
+
+    /// System program
+    pub system_program: Program<'info, System>,
+}
+
+/// Parameters for creating a proposal
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct CreateProposalParams {
+    /// Admin action the proposal authorizes
+    pub action: AdminAction,
+}
+
+/// Propose an admin action (counts as the proposer's approval)
+pub fn create_proposal(ctx: Context<CreateProposal>, params: CreateProposalParams) -> Result<()> {
+    if let AdminAction::ConfigureMultisig(multisig_params) = &params.action {
+        require!(multisig_params.is_valid(), LaunchrError::InvalidConfig);
+    }
+
+    let multisig = &mut ctx.accounts.multisig;
+    let proposal = &mut ctx.accounts.proposal;
+    let clock = Clock::get()?;
+
+    let signer_index = multisig
+        .signer_index(&ctx.accounts.proposer.key())
+        .ok_or(error!(LaunchrError::NotMultisigSigner))?;
+
+    proposal.multisig = multisig.key();
+    proposal.proposer = ctx.accounts.proposer.key();
+    proposal.index = multisig.proposal_count;
+    proposal.action = params.action;
+    proposal.approvals = 0;
+    proposal.approve(signer_index);
+    proposal.signer_set_version = multisig.signer_set_version;
+    proposal.created_at = clock.unix_timestamp;
+    proposal.expires_at = clock
+        .unix_timestamp
+        .saturating_add(multisig.proposal_lifetime_secs as i64);
+    proposal.executed = false;
+    proposal.bump = ctx.bumps.proposal;
+
+    multisig.proposal_count = multisig.proposal_count.saturating_add(1);
+
+    emit!(ProposalCreated {
+        multisig: multisig.key(),
+        proposal: proposal.key(),
+        index: proposal.index,
+        proposer: proposal.proposer,
+        action: proposal.action.clone(),
+        expires_at: proposal.expires_at,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Created multisig proposal #{}", proposal.index);
+
+    Ok(())
+}
+
+/// Approve a proposal
+#[derive(Accounts)]
+pub struct ApproveProposal<'info> {
+    /// Multisig signer approving
+    pub signer: Signer<'info>,
+
+    /// Global config
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump = config.bump
+    )]
+    pub config: Account<'info, Config>,
+
+    /// Multisig
+    #[account(
+        seeds = [MULTISIG_SEED, config.key().as_ref()],
+        bump = multisig.bump
+    )]
+    pub multisig: Account<'info, Multisig>,
+
+    /// Proposal to approve
+    #[account(
+        mut,
+        seeds = [PROPOSAL_SEED, multisig.key().as_ref(), &proposal.index.to_le_bytes()],
+        bump = proposal.bump
+    )]
+    pub proposal: Account<'info, Proposal>,
+}
+
+/// Add the signer's approval to an open proposal
+pub fn approve_proposal(ctx: Context<ApproveProposal>) -> Result<()> {
+    let multisig = &ctx.accounts.multisig;
+    let proposal = &mut ctx.accounts.proposal;
+    let clock = Clock::get()?;
+
+    let signer_index = multisig
+        .signer_index(&ctx.accounts.signer.key())
+        .ok_or(error!(LaunchrError::NotMultisigSigner))?;
+    require!(proposal.is_open(multisig, clock.unix_timestamp), LaunchrError::ProposalNotOpen);
+
+    proposal.approve(signer_index);
+
+    emit!(ProposalApproved {
+        multisig: multisig.key(),
+        proposal: proposal.key(),
+        index: proposal.index,
+        signer: ctx.accounts.signer.key(),
+        approvals: proposal.approval_count() as u8,
+        threshold: multisig.threshold,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Proposal #{} approvals: {}/{}",
+        proposal.index,
+        proposal.approval_count(),
+        multisig.threshold
+    );
+
+    Ok(())
+}
+
+/// Close a finished proposal
+#[derive(Accounts)]
+pub struct CloseProposal<'info> {
+    /// Anyone can close an executed, expired or stale proposal
+    pub closer: Signer<'info>,
+
+    /// Global config
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump = config.bump
+    )]
+    pub config: Account<'info, Config>,
+
+    /// Multisig
+    #[account(
+        seeds = [MULTISIG_SEED, config.key().as_ref()],
+        bump = multisig.bump
+    )]
+    pub multisig: Account<'info, Multisig>,
+
+    /// Proposal (closed to proposer)
+    #[account(
+        mut,
+        close = proposer,
+        seeds = [PROPOSAL_SEED, multisig.key().as_ref(), &proposal.index.to_le_bytes()],
+        bump = proposal.bump
+    )]
+    pub proposal: Account<'info, Proposal>,
+
+    /// Account that created the proposal
+    /// CHECK: Validated against proposal.proposer
+    #[account(
+        mut,
+        constraint = proposer.key() == proposal.proposer @ LaunchrError::Unauthorized
+    )]
+    pub proposer: UncheckedAccount<'info>,
+}
+
+/// Close a proposal that can no longer execute, returning rent to the proposer
+pub fn close_proposal(ctx: Context<CloseProposal>) -> Result<()> {
+    let proposal = &ctx.accounts.proposal;
+    let clock = Clock::get()?;
+
+    require!(
+        !proposal.is_open(&ctx.accounts.multisig, clock.unix_timestamp),
+        LaunchrError::ProposalStillOpen
+    );
+
+    emit!(ProposalClosed {
+        multisig: ctx.accounts.multisig.key(),
+        proposal: proposal.key(),
+        index: proposal.index,
+        executed: proposal.executed,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Closed multisig proposal #{}", proposal.index);
+
+    Ok(())
+}
+
+/// Event emitted when the multisig is configured
+#[event]
+pub struct MultisigConfigured {
+    pub multisig: Pubkey,
+    pub signers: Vec<Pubkey>,
+    pub threshold: u8,
+    pub proposal_lifetime_secs: u32,
+    pub enabled: bool,
+    pub signer_set_version: u32,
+    pub configured_by: Pubkey,
+    pub timestamp: i64,
+}
+
+/// Event emitted when a proposal is created
+#[event]
+pub struct ProposalCreated {
+    pub multisig: Pubkey,
+    pub proposal: Pubkey,
+    pub index: u64,
+    pub proposer: Pubkey,
+    pub action: AdminAction,
+    pub expires_at: i64,
+    pub timestamp: i64,
+}
+
+/// Event emitted when a signer approves a proposal
+#[event]
+pub struct ProposalApproved {
+    pub multisig: Pubkey,
+    pub proposal: Pubkey,
+    pub index: u64,
+    pub signer: Pubkey,
+    pub approvals: u8,
+    pub threshold: u8,
+    pub timestamp: i64,
+}
+
+/// Event emitted when an approved proposal's action runs
+#[event]
+pub struct ProposalExecuted {
+    pub multisig: Pubkey,
+    pub proposal: Pubkey,
+    pub index: u64,
+    pub executor: Pubkey,
+    pub timestamp: i64,
+}
+
+/// Event emitted when a proposal is closed
+#[event]
+pub struct ProposalClosed {
+    pub multisig: Pubkey,
+    pub proposal: Pubkey,
+    pub index: u64,
+    pub executed: bool,
+    pub timestamp: i64,
+}
@@ -0,0 +1,253 @@
+//! Launchr - Position Maintenance
+//!
+//! Upkeep of user positions against on-chain token balances.
+//! `resync_holder` recounts a position in `Launch.holder_count` from the
+//! owner's associated token account, which catches holders whose tokens
+//! moved by transfer rather than through the curve. `sync_position` goes
+//! further and moves the tracked balance itself to match the token account.
+//! Both read the token account through `ReconcilePosition`, which pins the
+//! token program to the mint's owner so a wrong program can't fake an empty account.
+//! `close_position` lets an exited (or graduated) trader reclaim the
+//! position's rent.
+
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::get_associated_token_address_with_program_id;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+use crate::seeds::*;
+use crate::state::*;
+use crate::math::LaunchrError;
+
+/// Reconcile a position with its owner's token account
+#[derive(Accounts)]
+pub struct ReconcilePosition<'info> {
+    /// Launch account
+    #[account(
+        mut,
+        seeds = [LAUNCH_SEED, launch.mint.as_ref()],
+        bump = launch.bump
+    )]
+    pub launch: Box<Account<'info, Launch>>,
+
+    /// Position to reconcile
+    #[account(
+        mut,
+        seeds = [USER_POSITION_SEED, launch.key().as_ref(), user_position.user.as_ref()],
+        bump = user_position.bump
+    )]
+    pub user_position: Account<'info, UserPosition>,
+
+    /// Launch mint, tying `token_program` to the program that owns it
+    #[account(
+        address = launch.mint @ LaunchrError::InvalidConfig,
+        mint::token_program = token_program,
+    )]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    /// Position owner's associated token account (may be closed)
+    /// CHECK: Address checked against the derived ATA; read only if initialized
+    #[account(
+        address = get_associated_token_address_with_program_id(
+            &user_position.user,
+            &launch.mint,
+            &token_program.key(),
+        ) @ LaunchrError::InvalidConfig
+    )]
+    pub user_token_account: UncheckedAccount<'info>,
+
+    /// Token program (SPL Token or Token-2022, matching the mint)
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+/// Count the position as a holder iff its owner's token account holds tokens
+pub fn resync_holder(ctx: Context<ReconcilePosition>) -> Result<()> {
+    let token_balance = token_account_balance(
+        &ctx.accounts.user_token_account,
+        &ctx.accounts.token_program.key(),
+        &ctx.accounts.launch.mint,
+    )?;
+
+    let launch = &mut ctx.accounts.launch;
+    let user_position = &mut ctx.accounts.user_position;
+    let was_holder = user_position.is_holder;
+    launch.set_holder(user_position, token_balance > 0);
+
+    emit!(HolderResynced {
+        launch: launch.key(),
+        user: user_position.user,
+        token_balance,
+        was_holder,
+        is_holder: user_position.is_holder,
+        holder_count: launch.holder_count,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    msg!("Holder resync for {}: {} tokens, holders: {}",
+        user_position.user,
+        token_balance,
+        launch.holder_count
+    );
+
+    Ok(())
+}
+
+/// Set a position's balance to its owner's token account balance
+///
+/// The difference is recorded as a transfer in (zero-cost lot) or out
+/// (proportional cost-basis reduction).
+pub fn sync_position(ctx: Context<ReconcilePosition>) -> Result<()> {
+    let actual_balance = token_account_balance(
+        &ctx.accounts.user_token_account,
+        &ctx.accounts.token_program.key(),
+        &ctx.accounts.launch.mint,
+    )?;
+
+    let launch = &mut ctx.accounts.launch;
+    let user_position = &mut ctx.accounts.user_position;
+    let old_balance = user_position.token_balance;
+    let (tokens_in, tokens_out) = user_position.sync_balance(actual_balance);
+    launch.update_holder(user_position);
+
+    emit!(PositionSynced {
+        launch: launch.key(),
+        user: user_position.user,
+        old_balance,
+        new_balance: user_position.token_balance,
+        tokens_transferred_in: tokens_in,
+        tokens_transferred_out: tokens_out,
+        cost_basis: user_position.cost_basis,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    msg!("Position synced for {}: {} -> {} tokens",
+        user_position.user,
+        old_balance,
+        user_position.token_balance
+    );
+
+    Ok(())
+}
+
+/// Close a position and reclaim its rent
+#[derive(Accounts)]
+pub struct ClosePosition<'info> {
+    /// Position owner (receives the rent)
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    /// Launch account
+    #[account(
+        mut,
+        seeds = [LAUNCH_SEED, launch.mint.as_ref()],
+        bump = launch.bump
+    )]
+    pub launch: Box<Account<'info, Launch>>,
+
+    /// Position to close
+    #[account(
+        mut,
+        close = user,
+        seeds = [USER_POSITION_SEED, launch.key().as_ref(), user.key().as_ref()],
+        bump = user_position.bump,
+        constraint = user_position.can_close(launch.status == LaunchStatus::Graduated)
+            @ LaunchrError::PositionNotEmpty
+    )]
+    pub user_position: Account<'info, UserPosition>,
+}
+
+/// Close an empty or graduated position, emitting its final stats
+pub fn close_position(ctx: Context<ClosePosition>) -> Result<()> {
+    let launch = &mut ctx.accounts.launch;
+    let user_position = &mut ctx.accounts.user_position;
+    let now = Clock::get()?.unix_timestamp;
+
+    // Closing resets `tokens_bought`, which the per-wallet sniper cap is measured by
+    require!(
+        launch.sniper_max_wallet_tokens(now).is_none(),
+        LaunchrError::SniperWindowActive
+    );
+
+    // Holder counts are frozen at graduation
+    if launch.status != LaunchStatus::Graduated {
+        launch.set_holder(user_position, false);
+    }
+
+    emit!(PositionClosed {
+        launch: launch.key(),
+        user: user_position.user,
+        tokens_bought: user_position.tokens_bought,
+        tokens_sold: user_position.tokens_sold,
+        token_balance: user_position.token_balance,
+        sol_spent: user_position.sol_spent,
+        sol_received: user_position.sol_received,
+        realized_pnl: user_position.realized_pnl(),
+        buy_count: user_position.buy_count,
+        sell_count: user_position.sell_count,
+        first_trade_at: user_position.first_trade_at,
+        last_trade_at: user_position.last_trade_at,
+        timestamp: now,
+    });
+
+    msg!("Position closed for {}: realized PnL {} lamports",
+        user_position.user,
+        user_position.realized_pnl()
+    );
+
+    Ok(())
+}
+
+/// Balance of a token account for `mint`, or 0 if it is closed or uninitialized
+pub(crate) fn token_account_balance(
+    account: &AccountInfo,
+    token_program: &Pubkey,
+    mint: &Pubkey,
+) -> Result<u64> {
+    if account.owner != token_program || account.data_is_empty() {
+        return Ok(0);
+    }
+    let token_account = TokenAccount::try_deserialize(&mut &account.data.borrow()[..])?;
+    require!(token_account.mint == *mint, LaunchrError::InvalidConfig);
+    Ok(token_account.amount)
+}
+
+/// Event emitted when a position's holder status is reconciled
+#[event]
+pub struct HolderResynced {
+    pub launch: Pubkey,
+    pub user: Pubkey,
+    pub token_balance: u64,
+    pub was_holder: bool,
+    pub is_holder: bool,
+    pub holder_count: u32,
+    pub timestamp: i64,
+}
+
+/// Event emitted when a position's balance is synced with its token account
+#[event]
+pub struct PositionSynced {
+    pub launch: Pubkey,
+    pub user: Pubkey,
+    pub old_balance: u64,
+    pub new_balance: u64,
+    pub tokens_transferred_in: u64,
+    pub tokens_transferred_out: u64,
+    pub cost_basis: u64,
+    pub timestamp: i64,
+}
+
+/// Event emitted with a position's final stats when it is closed
+#[event]
+pub struct PositionClosed {
+    pub launch: Pubkey,
+    pub user: Pubkey,
+    pub tokens_bought: u64,
+    pub tokens_sold: u64,
+    pub token_balance: u64,
+    pub sol_spent: u64,
+    pub sol_received: u64,
+    pub realized_pnl: i64,
+    pub buy_count: u32,
+    pub sell_count: u32,
+    pub first_trade_at: i64,
+    pub last_trade_at: i64,
+    pub timestamp: i64,
+}
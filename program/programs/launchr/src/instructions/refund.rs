@@ -0,0 +1,236 @@
+//! Launchr - Refunds
+//!
+//! Refund mode for launches that never reach their graduation threshold.
+//! Once a launch's refund window has passed, anyone can flip it into
+//! refund mode and holders burn their tokens for a pro-rata share of the
+//! SOL left on the curve.
+
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use anchor_spl::token_interface::{self, Burn, Mint, TokenAccount, TokenInterface};
+use crate::seeds::*;
+use crate::state::*;
+use crate::math::LaunchrError;
+
+/// Put an expired launch into refund mode
+#[derive(Accounts)]
+pub struct EnableRefunds<'info> {
+    /// Anyone can enable refunds once the launch has expired
+    pub payer: Signer<'info>,
+
+    /// Launch account
+    #[account(
+        mut,
+        seeds = [LAUNCH_SEED, launch.mint.as_ref()],
+        bump = launch.bump
+    )]
+    pub launch: Box<Account<'info, Launch>>,
+}
+
+/// Enable refunds on an expired launch
+pub fn enable_refunds(ctx: Context<EnableRefunds>) -> Result<()> {
+    let launch = &mut ctx.accounts.launch;
+    let clock = Clock::get()?;
+
+    require!(
+        launch.status == LaunchStatus::Active,
+        LaunchrError::LaunchNotActive
+    );
+    require!(
+        launch.can_enable_refunds(clock.unix_timestamp),
+        LaunchrError::LaunchNotExpired
+    );
+
+    launch.status = LaunchStatus::Refunding;
+
+    emit!(RefundsEnabled {
+        launch: launch.key(),
+        mint: launch.mint,
+        refundable_sol: launch.real_sol_reserve,
+        circulating_tokens: launch.tokens_sold,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Refunds enabled for {} ({})", launch.name_str(), launch.symbol_str());
+    msg!("Refundable: {} SOL across {} tokens",
+        launch.real_sol_reserve as f64 / 1e9,
+        launch.tokens_sold as f64 / 1e9
+    );
+
+    Ok(())
+}
+
+/// Redeem tokens for a pro-rata share of the curve SOL
+#[derive(Accounts)]
+pub struct RedeemRefund<'info> {
+    /// Token holder
+    #[account(mut)]
+    pub holder: Signer<'info>,
+
+    /// Launch account
+    #[account(
+        mut,
+        seeds = [LAUNCH_SEED, launch.mint.as_ref()],
+        bump = launch.bump,
+        constraint = launch.status == LaunchStatus::Refunding @ LaunchrError::RefundsNotActive
+    )]
+    pub launch: Box<Account<'info, Launch>>,
+
+    /// Token mint
+    #[account(
+        mut,
+        mint::token_program = token_program,
+        constraint = mint.key() == launch.mint @ LaunchrError::InvalidConfig
+    )]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    /// SOL curve vault (source of refunds)
+    /// CHECK: PDA holding SOL
+    #[account(
+        mut,
+        seeds = [CURVE_VAULT_SEED, launch.key().as_ref()],
+        bump
+    )]
+    pub curve_vault: UncheckedAccount<'info>,
+
+    /// Holder's token account
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = holder,
+        associated_token::token_program = token_program,
+    )]
+    pub holder_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    /// Holder's position (created if the holder never traded on the curve)
+    #[account(
+        init_if_needed,
+        payer = holder,
+        space = UserPosition::LEN,
+        seeds = [USER_POSITION_SEED, launch.key().as_ref(), holder.key().as_ref()],
+        bump
+    )]
+    pub user_position: Account<'info, UserPosition>,
+
+    /// Token program (SPL Token or Token-2022, matching the mint)
+    pub token_program: Interface<'info, TokenInterface>,
+
+    /// System program
+    pub system_program: Program<'info, System>,
+}
+
+/// Parameters for redeeming a refund
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct RedeemRefundParams {
+    /// Amount of tokens to burn
+    pub token_amount: u64,
+}
+
+/// Burn tokens and receive a pro-rata share of the curve SOL
+pub fn redeem_refund(ctx: Context<RedeemRefund>, params: RedeemRefundParams) -> Result<()> {
+    let launch = &mut ctx.accounts.launch;
+    let clock = Clock::get()?;
+
+    require!(params.token_amount > 0, LaunchrError::InvalidAmount);
+    require!(
+        ctx.accounts.holder_token_account.amount >= params.token_amount,
+        LaunchrError::InvalidAmount
+    );
+    require!(
+        params.token_amount <= launch.tokens_sold,
+        LaunchrError::InvalidAmount
+    );
+
+    // Pro-rata share rounds down; never dip into the vault's rent-exempt minimum
+    let sol_refunded = launch.refund_payout(
+        params.token_amount,
+        ctx.accounts.curve_vault.lamports(),
+        Rent::get()?.minimum_balance(0),
+    );
+
+    token_interface::burn(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            Burn {
+                mint: ctx.accounts.mint.to_account_info(),
+                from: ctx.accounts.holder_token_account.to_account_info(),
+                authority: ctx.accounts.holder.to_account_info(),
+            },
+        ),
+        params.token_amount,
+    )?;
+
+    if sol_refunded > 0 {
+        let launch_key = launch.key();
+        let curve_vault_bump = ctx.bumps.curve_vault;
+        let curve_vault_seeds: &[&[u8]] = &[
+            CURVE_VAULT_SEED,
+            launch_key.as_ref(),
+            &[curve_vault_bump],
+        ];
+
+        system_program::transfer(
+            CpiContext::new_with_signer(
+                ctx.accounts.system_program.to_account_info(),
+                system_program::Transfer {
+                    from: ctx.accounts.curve_vault.to_account_info(),
+                    to: ctx.accounts.holder.to_account_info(),
+                },
+                &[curve_vault_seeds],
+            ),
+            sol_refunded,
+        )?;
+    }
+
+    launch.record_refund(params.token_amount, sol_refunded);
+
+    // Update holder position (opened here for tokens received by transfer)
+    let user_position = &mut ctx.accounts.user_position;
+    if user_position.is_new() {
+        user_position.init(
+            launch.key(),
+            ctx.accounts.holder.key(),
+            ctx.bumps.user_position,
+            clock.unix_timestamp,
+        );
+    }
+    user_position.record_sell(params.token_amount, sol_refunded, clock.unix_timestamp);
+    launch.update_holder(user_position);
+
+    emit!(RefundRedeemed {
+        launch: launch.key(),
+        holder: ctx.accounts.holder.key(),
+        tokens_burned: params.token_amount,
+        sol_refunded,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Refund redeemed: {} tokens -> {} SOL",
+        params.token_amount as f64 / 1e9,
+        sol_refunded as f64 / 1e9
+    );
+
+    Ok(())
+}
+
+/// Event emitted when a launch enters refund mode
+#[event]
+pub struct RefundsEnabled {
+    pub launch: Pubkey,
+    pub mint: Pubkey,
+    /// Curve SOL available for refunds
+    pub refundable_sol: u64,
+    /// Tokens eligible for redemption
+    pub circulating_tokens: u64,
+    pub timestamp: i64,
+}
+
+/// Event emitted when a holder redeems a refund
+#[event]
+pub struct RefundRedeemed {
+    pub launch: Pubkey,
+    pub holder: Pubkey,
+    pub tokens_burned: u64,
+    pub sol_refunded: u64,
+    pub timestamp: i64,
+}
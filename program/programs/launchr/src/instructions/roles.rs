@@ -0,0 +1,148 @@
+//! Launchr - Role Management
+//!
+//! Admin (or the admin multisig) grants and revokes delegated protocol roles.
+
+use anchor_lang::prelude::*;
+use crate::seeds::*;
+use crate::state::*;
+use crate::math::LaunchrError;
+use crate::instructions::multisig::authorize_admin_action;
+
+/// Grant or revoke a role
+#[derive(Accounts)]
+pub struct ManageRole<'info> {
+    /// Admin, or a multisig signer executing an approved proposal
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    /// Global config
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump = config.bump
+    )]
+    pub config: Account<'info, Config>,
+
+    /// Role assignments (created on first grant)
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = Roles::LEN,
+        seeds = [ROLES_SEED, config.key().as_ref()],
+        bump
+    )]
+    pub roles: Account<'info, Roles>,
+
+    /// Admin multisig (required in multisig mode)
+    #[account(
+        seeds = [MULTISIG_SEED, config.key().as_ref()],
+        bump = multisig.bump
+    )]
+    pub multisig: Option<Account<'info, Multisig>>,
+
+    /// Approved proposal (required in multisig mode)
+    #[account(mut)]
+    pub proposal: Option<Account<'info, Proposal>>,
+
+    /// System program
+    pub system_program: Program<'info, System>,
+}
+
+/// Parameters for granting a role
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct GrantRoleParams {
+    /// Role to grant
+    pub role: Role,
+    /// Account receiving the role (replaces any current holder)
+    pub holder: Pubkey,
+}
+
+/// Grant a role to an account
+pub fn grant_role(ctx: Context<ManageRole>, params: GrantRoleParams) -> Result<()> {
+    require!(params.holder != Pubkey::default(), LaunchrError::InvalidConfig);
+
+    authorize_admin_action(
+        &ctx.accounts.config,
+        &ctx.accounts.config.admin,
+        &ctx.accounts.authority.key(),
+        ctx.accounts.multisig.as_ref(),
+        ctx.accounts.proposal.as_mut(),
+        AdminAction::GrantRole { role: params.role, holder: params.holder },
+    )?;
+
+    let roles = &mut ctx.accounts.roles;
+    roles.config = ctx.accounts.config.key();
+    roles.bump = ctx.bumps.roles;
+
+    let previous_holder = roles.holder(params.role);
+    roles.set_holder(params.role, params.holder);
+
+    emit!(RoleGranted {
+        role: params.role,
+        holder: params.holder,
+        previous_holder,
+        granted_by: ctx.accounts.authority.key(),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    msg!("Granted {:?} to {}", params.role, params.holder);
+
+    Ok(())
+}
+
+/// Parameters for revoking a role
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct RevokeRoleParams {
+    /// Role to revoke
+    pub role: Role,
+}
+
+/// Revoke a role from its current holder
+pub fn revoke_role(ctx: Context<ManageRole>, params: RevokeRoleParams) -> Result<()> {
+    authorize_admin_action(
+        &ctx.accounts.config,
+        &ctx.accounts.config.admin,
+        &ctx.accounts.authority.key(),
+        ctx.accounts.multisig.as_ref(),
+        ctx.accounts.proposal.as_mut(),
+        AdminAction::RevokeRole { role: params.role },
+    )?;
+
+    let roles = &mut ctx.accounts.roles;
+    roles.config = ctx.accounts.config.key();
+    roles.bump = ctx.bumps.roles;
+
+    let previous_holder = roles.holder(params.role);
+    require!(previous_holder != Pubkey::default(), LaunchrError::InvalidConfig);
+    roles.set_holder(params.role, Pubkey::default());
+
+    emit!(RoleRevoked {
+        role: params.role,
+        previous_holder,
+        revoked_by: ctx.accounts.authority.key(),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    msg!("Revoked {:?} from {}", params.role, previous_holder);
+
+    Ok(())
+}
+
+/// Event emitted when a role is granted
+#[event]
+pub struct RoleGranted {
+    pub role: Role,
+    pub holder: Pubkey,
+    /// Holder replaced by this grant (default if none)
+    pub previous_holder: Pubkey,
+    pub granted_by: Pubkey,
+    pub timestamp: i64,
+}
+
+/// Event emitted when a role is revoked
+#[event]
+pub struct RoleRevoked {
+    pub role: Role,
+    pub previous_holder: Pubkey,
+    pub revoked_by: Pubkey,
+    pub timestamp: i64,
+}
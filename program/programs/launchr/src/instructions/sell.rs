@@ -1,14 +1,16 @@
 //! Launchr - Sell Tokens
 //!
 //! Sell tokens back to the bonding curve for SOL.
+//! Sellers who received their tokens by transfer get a position opened on
+//! their first sell; those tokens carry no cost basis.
 
 use anchor_lang::prelude::*;
 use anchor_lang::system_program;
-use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+use anchor_spl::token_interface::{self, Mint, TokenAccount, TokenInterface, TransferChecked};
 use crate::seeds::*;
 use crate::state::*;
-use crate::math::{bonding_curve, LaunchrError};
-use crate::instructions::buy::TradeExecuted;
+use crate::math::{bonding_curve, LaunchrError, SwapResult};
+use crate::instructions::buy::{check_deadline, check_price_impact, TradeExecuted};
 
 /// Minimum lamports to keep in curve vault for rent exemption
 const CURVE_VAULT_RENT_MINIMUM: u64 = 890_880;
@@ -34,7 +36,10 @@ pub struct Sell<'info> {
         mut,
         seeds = [LAUNCH_SEED, launch.mint.as_ref()],
         bump = launch.bump,
-        constraint = launch.is_tradeable() @ LaunchrError::LaunchNotActive
+        constraint = launch.status == LaunchStatus::Active @ LaunchrError::LaunchNotActive,
+        constraint = launch.is_tradeable(Clock::get()?.unix_timestamp) @ LaunchrError::TradingNotStarted,
+        constraint = !launch.is_paused() @ LaunchrError::LaunchPaused,
+        constraint = !launch.is_delisted() @ LaunchrError::LaunchDelisted
     )]
     pub launch: Box<Account<'info, Launch>>,
 
@@ -53,7 +58,7 @@ pub struct Sell<'info> {
         bump,
         constraint = token_vault.mint == launch.mint @ LaunchrError::InvalidConfig
     )]
-    pub token_vault: Account<'info, TokenAccount>,
+    pub token_vault: InterfaceAccount<'info, TokenAccount>,
 
     /// SOL curve vault (source of SOL)
     /// CHECK: PDA for holding SOL
@@ -69,22 +74,24 @@ pub struct Sell<'info> {
         mut,
         associated_token::mint = mint,
         associated_token::authority = seller,
+        associated_token::token_program = token_program,
     )]
-    pub seller_token_account: Account<'info, TokenAccount>,
+    pub seller_token_account: InterfaceAccount<'info, TokenAccount>,
 
     /// Token mint
     #[account(
+        mint::token_program = token_program,
         constraint = mint.key() == launch.mint @ LaunchrError::InvalidConfig
     )]
-    pub mint: Account<'info, anchor_spl::token::Mint>,
+    pub mint: InterfaceAccount<'info, Mint>,
 
-    /// User position
+    /// User position (created if the seller never bought)
     #[account(
-        mut,
+        init_if_needed,
+        payer = seller,
+        space = UserPosition::LEN,
         seeds = [USER_POSITION_SEED, launch.key().as_ref(), seller.key().as_ref()],
-        bump = user_position.bump,
-        constraint = user_position.launch == launch.key() @ LaunchrError::InvalidConfig,
-        constraint = user_position.user == seller.key() @ LaunchrError::Unauthorized
+        bump
     )]
     pub user_position: Account<'info, UserPosition>,
 
@@ -105,8 +112,8 @@ pub struct Sell<'info> {
     )]
     pub creator: UncheckedAccount<'info>,
 
-    /// Token program
-    pub token_program: Program<'info, Token>,
+    /// Token program (SPL Token or Token-2022, matching the mint)
+    pub token_program: Interface<'info, TokenInterface>,
 
     /// System program
     pub system_program: Program<'info, System>,
@@ -121,25 +128,49 @@ pub struct SellParams {
     pub min_sol_out: u64,
 }
 
+/// Parameters for selling tokens with execution guards
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct SellV2Params {
+    /// Amount of tokens to sell
+    pub token_amount: u64,
+    /// Minimum SOL to receive (slippage protection)
+    pub min_sol_out: u64,
+    /// Unix timestamp after which the sell is rejected (optional)
+    pub deadline: Option<i64>,
+    /// Maximum price impact in basis points (optional)
+    pub max_price_impact_bps: Option<u16>,
+}
+
+/// Parameters for selling tokens for an exact SOL amount
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct SellExactOutParams {
+    /// Exact SOL to receive after fees (in lamports)
+    pub sol_out: u64,
+    /// Maximum tokens to spend (slippage protection)
+    pub max_tokens_in: u64,
+}
+
 /// Sell tokens back to the bonding curve
 pub fn sell(ctx: Context<Sell>, params: SellParams) -> Result<()> {
-    let launch = &mut ctx.accounts.launch;
-    let config = &mut ctx.accounts.config;
-    let user_position = &mut ctx.accounts.user_position;
-    let clock = Clock::get()?;
+    sell_v2(ctx, SellV2Params {
+        token_amount: params.token_amount,
+        min_sol_out: params.min_sol_out,
+        deadline: None,
+        max_price_impact_bps: None,
+    })
+}
 
-    // Verify seller has enough tokens
-    require!(
-        ctx.accounts.seller_token_account.amount >= params.token_amount,
-        LaunchrError::InsufficientLiquidity
-    );
+/// Sell tokens back to the bonding curve, guarded by a deadline and a price impact limit
+pub fn sell_v2(ctx: Context<Sell>, params: SellV2Params) -> Result<()> {
+    check_deadline(params.deadline)?;
+    let launch = &ctx.accounts.launch;
 
     // Calculate swap
     let swap_result = bonding_curve::calculate_sell(
         params.token_amount,
         launch.virtual_sol_reserve,
         launch.virtual_token_reserve,
-        config.protocol_fee_bps,
+        ctx.accounts.config.protocol_fee_bps,
         launch.creator_fee_bps,
     )?;
 
@@ -148,37 +179,116 @@ pub fn sell(ctx: Context<Sell>, params: SellParams) -> Result<()> {
         swap_result.amount_out >= params.min_sol_out,
         LaunchrError::SlippageExceeded
     );
+    check_price_impact(&swap_result, params.max_price_impact_bps)?;
+
+    let curve_vault_bump = ctx.bumps.curve_vault;
+    let user_position_bump = ctx.bumps.user_position;
+    settle_sell(
+        ctx.accounts,
+        curve_vault_bump,
+        user_position_bump,
+        params.token_amount,
+        swap_result.amount_out,
+        &swap_result,
+    )
+}
+
+/// Sell tokens back to the bonding curve for an exact SOL amount
+pub fn sell_exact_out(ctx: Context<Sell>, params: SellExactOutParams) -> Result<()> {
+    let launch = &ctx.accounts.launch;
+    let protocol_fee_bps = ctx.accounts.config.protocol_fee_bps;
+
+    // Tokens needed for the exact payout, rounded up
+    let token_amount = bonding_curve::calculate_tokens_for_sol_out(
+        params.sol_out,
+        launch.virtual_sol_reserve,
+        launch.virtual_token_reserve,
+        protocol_fee_bps,
+        launch.creator_fee_bps,
+    )?;
+
+    // Check slippage
+    require!(token_amount <= params.max_tokens_in, LaunchrError::SlippageExceeded);
+
+    // Fee legs and post-trade price for the rounded-up input
+    let swap_result = bonding_curve::calculate_sell(
+        token_amount,
+        launch.virtual_sol_reserve,
+        launch.virtual_token_reserve,
+        protocol_fee_bps,
+        launch.creator_fee_bps,
+    )?;
+    require!(
+        swap_result.amount_out >= params.sol_out,
+        LaunchrError::InsufficientOutput
+    );
+
+    let curve_vault_bump = ctx.bumps.curve_vault;
+    let user_position_bump = ctx.bumps.user_position;
+    settle_sell(
+        ctx.accounts,
+        curve_vault_bump,
+        user_position_bump,
+        token_amount,
+        params.sol_out,
+        &swap_result,
+    )
+}
+
+/// Move tokens and SOL for a priced sell and record it
+///
+/// `sol_out` is what the seller receives; it may be below
+/// `swap_result.amount_out` when the input was rounded up in the pool's favour.
+fn settle_sell(
+    accounts: &mut Sell,
+    curve_vault_bump: u8,
+    user_position_bump: u8,
+    token_amount: u64,
+    sol_out: u64,
+    swap_result: &SwapResult,
+) -> Result<()> {
+    let launch = &mut accounts.launch;
+    let config = &mut accounts.config;
+    let user_position = &mut accounts.user_position;
+    let clock = Clock::get()?;
+
+    // Verify seller has enough tokens
+    require!(
+        accounts.seller_token_account.amount >= token_amount,
+        LaunchrError::InsufficientLiquidity
+    );
 
     // Check sufficient SOL in vault (including rent-exempt minimum)
-    let total_sol_needed = swap_result.amount_out
+    let total_sol_needed = sol_out
         .checked_add(swap_result.protocol_fee)
         .and_then(|v| v.checked_add(swap_result.creator_fee))
         .ok_or(error!(LaunchrError::MathOverflow))?;
 
-    let vault_lamports = ctx.accounts.curve_vault.lamports();
+    let vault_lamports = accounts.curve_vault.lamports();
     require!(
         vault_lamports >= total_sol_needed.saturating_add(CURVE_VAULT_RENT_MINIMUM),
         LaunchrError::InsufficientLiquidity
     );
 
     // Transfer tokens from seller to vault
-    token::transfer(
+    token_interface::transfer_checked(
         CpiContext::new(
-            ctx.accounts.token_program.to_account_info(),
-            Transfer {
-                from: ctx.accounts.seller_token_account.to_account_info(),
-                to: ctx.accounts.token_vault.to_account_info(),
-                authority: ctx.accounts.seller.to_account_info(),
+            accounts.token_program.to_account_info(),
+            TransferChecked {
+                from: accounts.seller_token_account.to_account_info(),
+                mint: accounts.mint.to_account_info(),
+                to: accounts.token_vault.to_account_info(),
+                authority: accounts.seller.to_account_info(),
             },
         ),
-        params.token_amount,
+        token_amount,
+        accounts.mint.decimals,
     )?;
 
     // Transfer SOL from curve vault using system_program::transfer with PDA signer.
     // The curve_vault is owned by the System Program (SOL deposited via system_program::transfer
     // in buy), so direct lamport manipulation would fail with ExternalAccountLamportSpend.
     let launch_key = launch.key();
-    let curve_vault_bump = ctx.bumps.curve_vault;
     let curve_vault_seeds: &[&[u8]] = &[
         CURVE_VAULT_SEED,
         launch_key.as_ref(),
@@ -189,24 +299,24 @@ pub fn sell(ctx: Context<Sell>, params: SellParams) -> Result<()> {
     // Transfer SOL to seller
     system_program::transfer(
         CpiContext::new_with_signer(
-            ctx.accounts.system_program.to_account_info(),
+            accounts.system_program.to_account_info(),
             system_program::Transfer {
-                from: ctx.accounts.curve_vault.to_account_info(),
-                to: ctx.accounts.seller.to_account_info(),
+                from: accounts.curve_vault.to_account_info(),
+                to: accounts.seller.to_account_info(),
             },
             signer_seeds,
         ),
-        swap_result.amount_out,
+        sol_out,
     )?;
 
     // Transfer protocol fee to fee vault
     if swap_result.protocol_fee > 0 {
         system_program::transfer(
             CpiContext::new_with_signer(
-                ctx.accounts.system_program.to_account_info(),
+                accounts.system_program.to_account_info(),
                 system_program::Transfer {
-                    from: ctx.accounts.curve_vault.to_account_info(),
-                    to: ctx.accounts.fee_vault.to_account_info(),
+                    from: accounts.curve_vault.to_account_info(),
+                    to: accounts.fee_vault.to_account_info(),
                 },
                 signer_seeds,
             ),
@@ -218,10 +328,10 @@ pub fn sell(ctx: Context<Sell>, params: SellParams) -> Result<()> {
     if swap_result.creator_fee > 0 {
         system_program::transfer(
             CpiContext::new_with_signer(
-                ctx.accounts.system_program.to_account_info(),
+                accounts.system_program.to_account_info(),
                 system_program::Transfer {
-                    from: ctx.accounts.curve_vault.to_account_info(),
-                    to: ctx.accounts.creator.to_account_info(),
+                    from: accounts.curve_vault.to_account_info(),
+                    to: accounts.creator.to_account_info(),
                 },
                 signer_seeds,
             ),
@@ -230,30 +340,40 @@ pub fn sell(ctx: Context<Sell>, params: SellParams) -> Result<()> {
     }
 
     // Update launch state — pass total SOL leaving vault (payout + all fees)
-    launch.record_sell(params.token_amount, swap_result.amount_out, total_sol_needed);
-
-    // Update user position
-    user_position.record_sell(params.token_amount, swap_result.amount_out, clock.unix_timestamp);
+    launch.record_sell(token_amount, sol_out, total_sol_needed);
+
+    // Update user position (opened here for tokens received by transfer)
+    if user_position.is_new() {
+        user_position.init(
+            launch_key,
+            accounts.seller.key(),
+            user_position_bump,
+            clock.unix_timestamp,
+        );
+    }
+    user_position.record_sell(token_amount, sol_out, clock.unix_timestamp);
+    launch.update_holder(user_position);
 
     // Update global stats
-    config.record_trade(swap_result.amount_out, swap_result.protocol_fee);
+    config.record_trade(sol_out, swap_result.protocol_fee);
 
     // Emit event
     emit!(TradeExecuted {
-        launch: launch.key(),
-        trader: ctx.accounts.seller.key(),
+        launch: launch_key,
+        trader: accounts.seller.key(),
         is_buy: false,
-        sol_amount: swap_result.amount_out,
-        token_amount: params.token_amount,
+        sol_amount: sol_out,
+        token_amount,
         price: swap_result.price_after,
         protocol_fee: swap_result.protocol_fee,
         creator_fee: swap_result.creator_fee,
         timestamp: clock.unix_timestamp,
+        requested_sol_amount: sol_out,
     });
 
     msg!("Sell executed: {} tokens -> {} SOL",
-        params.token_amount as f64 / 1e9,
-        swap_result.amount_out as f64 / 1e9
+        token_amount as f64 / 1e9,
+        sol_out as f64 / 1e9
     );
     msg!("New price: {} lamports/token", swap_result.price_after);
     msg!("Price impact: {} bps", swap_result.price_impact_bps);
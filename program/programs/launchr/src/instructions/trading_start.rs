@@ -0,0 +1,75 @@
+//! Launchr - Trading Start
+//!
+//! Let a creator open a scheduled launch earlier than announced.
+//! The start can only be brought earlier, never pushed later, so holders can rely
+//! on the announced start as the latest moment trading opens.
+
+use anchor_lang::prelude::*;
+use crate::seeds::*;
+use crate::state::*;
+use crate::math::LaunchrError;
+
+/// Move a launch's trading start earlier
+#[derive(Accounts)]
+pub struct SetTradingStart<'info> {
+    /// Creator of the launch
+    pub creator: Signer<'info>,
+
+    /// Launch account
+    #[account(
+        mut,
+        seeds = [LAUNCH_SEED, launch.mint.as_ref()],
+        bump = launch.bump,
+        constraint = launch.creator == creator.key() @ LaunchrError::Unauthorized,
+        constraint = launch.status == LaunchStatus::Active @ LaunchrError::LaunchNotActive
+    )]
+    pub launch: Box<Account<'info, Launch>>,
+}
+
+/// Parameters for moving the trading start
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct SetTradingStartParams {
+    /// New trading start (unix timestamp, must not be later than the current one)
+    pub trading_starts_at: i64,
+}
+
+/// Bring a launch's trading start forward
+pub fn set_trading_start(ctx: Context<SetTradingStart>, params: SetTradingStartParams) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let launch = &mut ctx.accounts.launch;
+    let old_starts_at = launch.trading_starts_at;
+
+    require!(!launch.trading_started(now), LaunchrError::TradingStartNotEarlier);
+    require!(params.trading_starts_at < old_starts_at, LaunchrError::TradingStartNotEarlier);
+
+    // A start in the past just opens trading now
+    let new_starts_at = params.trading_starts_at.max(now);
+    launch.trading_starts_at = new_starts_at;
+
+    emit!(TradingStartUpdated {
+        launch: launch.key(),
+        mint: launch.mint,
+        old_starts_at,
+        new_starts_at,
+        timestamp: now,
+    });
+
+    msg!("Trading start for {} ({}): {} -> {}",
+        launch.name_str(),
+        launch.symbol_str(),
+        old_starts_at,
+        new_starts_at
+    );
+
+    Ok(())
+}
+
+/// Event emitted when a creator moves a launch's trading start
+#[event]
+pub struct TradingStartUpdated {
+    pub launch: Pubkey,
+    pub mint: Pubkey,
+    pub old_starts_at: i64,
+    pub new_starts_at: i64,
+    pub timestamp: i64,
+}
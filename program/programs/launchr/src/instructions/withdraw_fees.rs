@@ -0,0 +1,137 @@
+//! Launchr - Withdraw Protocol Fees
+//!
+//! Move accumulated treasury fees out of the protocol fee vault.
+
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use crate::seeds::*;
+use crate::state::*;
+use crate::math::LaunchrError;
+use crate::instructions::multisig::authorize_admin_action;
+
+/// Withdraw protocol fees from the fee vault
+#[derive(Accounts)]
+pub struct WithdrawProtocolFees<'info> {
+    /// Fee authority from config, or a multisig signer executing an approved proposal
+    pub fee_authority: Signer<'info>,
+
+    /// Global config
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump = config.bump
+    )]
+    pub config: Box<Account<'info, Config>>,
+
+    /// Fee vault holding protocol fees
+    /// CHECK: PDA for holding protocol fees
+    #[account(
+        mut,
+        seeds = [FEE_VAULT_SEED, config.key().as_ref()],
+        bump
+    )]
+    pub fee_vault: UncheckedAccount<'info>,
+
+    /// Destination for the withdrawn lamports
+    /// CHECK: Any account chosen by the fee authority
+    #[account(mut)]
+    pub destination: UncheckedAccount<'info>,
+
+    /// Admin multisig (required in multisig mode)
+    #[account(
+        seeds = [MULTISIG_SEED, config.key().as_ref()],
+        bump = multisig.bump
+    )]
+    pub multisig: Option<Account<'info, Multisig>>,
+
+    /// Approved proposal (required in multisig mode)
+    #[account(mut)]
+    pub proposal: Option<Account<'info, Proposal>>,
+
+    /// System program
+    pub system_program: Program<'info, System>,
+}
+
+/// Parameters for withdrawing protocol fees
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct WithdrawProtocolFeesParams {
+    /// Lamports to withdraw (None = everything above rent-exempt minimum)
+    pub amount: Option<u64>,
+}
+
+/// Withdraw protocol fees
+pub fn withdraw_protocol_fees(
+    ctx: Context<WithdrawProtocolFees>,
+    params: WithdrawProtocolFeesParams,
+) -> Result<()> {
+    authorize_admin_action(
+        &ctx.accounts.config,
+        &ctx.accounts.config.fee_authority,
+        &ctx.accounts.fee_authority.key(),
+        ctx.accounts.multisig.as_ref(),
+        ctx.accounts.proposal.as_mut(),
+        AdminAction::WithdrawProtocolFees {
+            amount: params.amount,
+            destination: ctx.accounts.destination.key(),
+        },
+    )?;
+
+    // Keep the vault rent-exempt so it can keep receiving fees
+    let rent_minimum = Rent::get()?.minimum_balance(0);
+    let vault_lamports = ctx.accounts.fee_vault.lamports();
+    let available = vault_lamports.saturating_sub(rent_minimum);
+
+    let amount = params.amount.unwrap_or(available);
+    require!(amount > 0, LaunchrError::InvalidAmount);
+    require!(amount <= available, LaunchrError::InsufficientLiquidity);
+
+    // The fee vault is owned by the System Program, so transfer with PDA signer
+    let config_key = ctx.accounts.config.key();
+    let fee_vault_bump = ctx.bumps.fee_vault;
+    let fee_vault_seeds: &[&[u8]] = &[
+        FEE_VAULT_SEED,
+        config_key.as_ref(),
+        &[fee_vault_bump],
+    ];
+    let signer_seeds = &[fee_vault_seeds];
+
+    system_program::transfer(
+        CpiContext::new_with_signer(
+            ctx.accounts.system_program.to_account_info(),
+            system_program::Transfer {
+                from: ctx.accounts.fee_vault.to_account_info(),
+                to: ctx.accounts.destination.to_account_info(),
+            },
+            signer_seeds,
+        ),
+        amount,
+    )?;
+
+    let clock = Clock::get()?;
+    let remaining = vault_lamports.saturating_sub(amount);
+
+    emit!(ProtocolFeesWithdrawn {
+        fee_authority: ctx.accounts.fee_authority.key(),
+        destination: ctx.accounts.destination.key(),
+        amount,
+        remaining,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Withdrew {} SOL in protocol fees to {}",
+        amount as f64 / 1e9,
+        ctx.accounts.destination.key()
+    );
+
+    Ok(())
+}
+
+/// Event emitted when protocol fees are withdrawn
+#[event]
+pub struct ProtocolFeesWithdrawn {
+    pub fee_authority: Pubkey,
+    pub destination: Pubkey,
+    pub amount: u64,
+    /// Lamports left in the fee vault (including rent-exempt minimum)
+    pub remaining: u64,
+    pub timestamp: i64,
+}
@@ -27,7 +27,7 @@
 //! 
 //! ## Program Flow
 //! 
-//! 1. Creator calls `create_launch` with token metadata
+//! 1. Creator calls `create_launch` (or `create_launch_2022`) with token metadata
 //! 2. Users buy/sell on bonding curve via `buy`/`sell`
 //! 3. When threshold reached, anyone can call `graduate`
 //! 4. Liquidity migrates to Orbit Finance DLMM
@@ -41,17 +41,29 @@
 //! ├── seeds.rs            # All PDA seeds
 //! ├── state/              # Account structures
 //! │   ├── config.rs       # Global configuration
+//! │   ├── config_change.rs # Queued config changes
 //! │   ├── launch.rs       # Individual launch state
-//! │   └── user_position.rs # User trading positions
+//! │   ├── user_position.rs # User trading positions
+//! │   ├── roles.rs        # Delegated protocol roles
+//! │   └── multisig.rs     # Admin multisig and proposals
 //! ├── math/               # Calculations
 //! │   ├── bonding_curve.rs # AMM math
 //! │   └── orbit_math.rs   # DLMM graduation math
 //! └── instructions/       # Program instructions
 //!     ├── init_config.rs  # Initialize protocol
+//!     ├── config_timelock.rs # Timelocked config changes
+//!     ├── roles.rs        # Grant and revoke roles
+//!     ├── multisig.rs     # Admin multisig proposals
+//!     ├── moderation.rs   # Per-launch pause and delisting
 //!     ├── create_launch.rs # Create new launch
 //!     ├── buy.rs          # Buy tokens
 //!     ├── sell.rs         # Sell tokens
-//!     └── graduate.rs     # Graduate to Orbit
+//!     ├── graduate.rs     # Graduate to Orbit
+//!     ├── withdraw_fees.rs # Withdraw protocol fees
+//!     ├── cancel_launch.rs # Creator cancellation
+//!     ├── trading_start.rs # Scheduled trading start
+//!     ├── position.rs     # Position maintenance
+//!     └── refund.rs       # Refunds for expired launches
 //! ```
 
 use anchor_lang::prelude::*;
@@ -85,7 +97,12 @@ pub mod launchr {
     /// Update configuration parameters
     /// 
     /// Allows the admin to update protocol parameters such as fees,
-    /// graduation thresholds, and pause states.
+    /// graduation thresholds, and pause states. Pausers, fee managers and
+    /// parameter managers may update the fields covered by their role; in
+    /// multisig mode only pause flags skip the proposal, and the fee
+    /// authority is always admin-only.
+    /// When a config timelock is set, only pause flags apply here; other
+    /// changes must be queued.
     /// 
     /// # Arguments
     /// * `ctx` - Update config context  
@@ -94,18 +111,157 @@ pub mod launchr {
         instructions::init_config::update_config(ctx, params)
     }
 
-    /// Transfer admin authority to a new account
+    /// Queue a config change behind the config timelock
+    /// 
+    /// Stores the proposed changes in a PDA that can be executed once
+    /// `config_timelock_secs` has elapsed. Only one change can be queued at a time.
+    /// 
+    /// # Arguments
+    /// * `ctx` - Queue config change context
+    /// * `params` - Proposed configuration values
+    pub fn queue_config_change(ctx: Context<QueueConfigChange>, params: UpdateConfigParams) -> Result<()> {
+        instructions::config_timelock::queue_config_change(ctx, params)
+    }
+
+    /// Execute a queued config change
+    /// 
+    /// Can be called by anyone once the timelock has elapsed.
+    /// 
+    /// # Arguments
+    /// * `ctx` - Execute config change context
+    pub fn execute_config_change(ctx: Context<ExecuteConfigChange>) -> Result<()> {
+        instructions::config_timelock::execute_config_change(ctx)
+    }
+
+    /// Cancel a queued config change
+    /// 
+    /// Callable by the admin, or by the account that queued the change. In
+    /// multisig mode it always needs an approved proposal.
+    /// 
+    /// # Arguments
+    /// * `ctx` - Cancel config change context
+    pub fn cancel_config_change(ctx: Context<CancelConfigChange>) -> Result<()> {
+        instructions::config_timelock::cancel_config_change(ctx)
+    }
+
+    /// Grant a protocol role
+    /// 
+    /// Assigns the pauser, fee manager, parameter manager or moderator role
+    /// to an account. Only callable by the admin (or via multisig proposal).
+    /// 
+    /// # Arguments
+    /// * `ctx` - Manage role context
+    /// * `params` - Role and holder
+    pub fn grant_role(ctx: Context<ManageRole>, params: GrantRoleParams) -> Result<()> {
+        instructions::roles::grant_role(ctx, params)
+    }
+
+    /// Revoke a protocol role
+    /// 
+    /// # Arguments
+    /// * `ctx` - Manage role context
+    /// * `params` - Role to revoke
+    pub fn revoke_role(ctx: Context<ManageRole>, params: RevokeRoleParams) -> Result<()> {
+        instructions::roles::revoke_role(ctx, params)
+    }
+
+    /// Configure the admin multisig
+    /// 
+    /// Sets the signer set, approval threshold and proposal lifetime, and
+    /// toggles multisig mode. While enabled, admin actions (and fee
+    /// withdrawals) only run with an approved proposal for that exact action.
+    /// Reconfiguring an enabled multisig itself needs an approved proposal.
+    /// 
+    /// # Arguments
+    /// * `ctx` - Configure multisig context
+    /// * `params` - Signers, threshold, proposal lifetime and enabled flag
+    pub fn configure_multisig(ctx: Context<ConfigureMultisig>, params: ConfigureMultisigParams) -> Result<()> {
+        instructions::multisig::configure_multisig(ctx, params)
+    }
+
+    /// Create a multisig proposal for an admin action
+    /// 
+    /// Only callable by a multisig signer; counts as their approval.
+    /// 
+    /// # Arguments
+    /// * `ctx` - Create proposal context
+    /// * `params` - Admin action to authorize
+    pub fn create_proposal(ctx: Context<CreateProposal>, params: CreateProposalParams) -> Result<()> {
+        instructions::multisig::create_proposal(ctx, params)
+    }
+
+    /// Approve a multisig proposal
+    /// 
+    /// # Arguments
+    /// * `ctx` - Approve proposal context
+    pub fn approve_proposal(ctx: Context<ApproveProposal>) -> Result<()> {
+        instructions::multisig::approve_proposal(ctx)
+    }
+
+    /// Close an executed, expired or stale proposal
+    /// 
+    /// Can be called by anyone; rent returns to the proposer.
+    /// 
+    /// # Arguments
+    /// * `ctx` - Close proposal context
+    pub fn close_proposal(ctx: Context<CloseProposal>) -> Result<()> {
+        instructions::multisig::close_proposal(ctx)
+    }
+
+    /// Set moderation flags on a single launch
+    /// 
+    /// Pauses trading on, delists or hides one launch. Paused and delisted
+    /// launches can't be bought, sold or graduated. Callable by the
+    /// moderator or the admin. In multisig mode the moderator can only
+    /// toggle the trading pause without a proposal.
+    /// 
+    /// # Arguments
+    /// * `ctx` - Set launch flags context
+    /// * `params` - New flags value
+    pub fn set_launch_flags(ctx: Context<SetLaunchFlags>, params: SetLaunchFlagsParams) -> Result<()> {
+        instructions::moderation::set_launch_flags(ctx, params)
+    }
+
+    /// Propose a new admin authority
+    /// 
+    /// First step of a two-step transfer. The proposed key takes over only
+    /// once it signs `accept_admin`.
+    /// 
+    /// # Arguments
+    /// * `ctx` - Propose admin context
+    pub fn propose_admin(ctx: Context<ProposeAdmin>) -> Result<()> {
+        instructions::init_config::propose_admin(ctx)
+    }
+
+    /// Accept a pending admin transfer
+    /// 
+    /// Must be signed by the proposed admin.
+    /// 
+    /// # Arguments
+    /// * `ctx` - Accept admin context
+    pub fn accept_admin(ctx: Context<AcceptAdmin>) -> Result<()> {
+        instructions::init_config::accept_admin(ctx)
+    }
+
+    /// Cancel a pending admin transfer
     /// 
     /// # Arguments
-    /// * `ctx` - Transfer admin context
-    pub fn transfer_admin(ctx: Context<TransferAdmin>) -> Result<()> {
-        instructions::init_config::transfer_admin(ctx)
+    /// * `ctx` - Cancel admin transfer context
+    pub fn cancel_admin_transfer(ctx: Context<CancelAdminTransfer>) -> Result<()> {
+        instructions::init_config::cancel_admin_transfer(ctx)
     }
 
     /// Create a new token launch on the bonding curve
     /// 
-    /// Creates a new SPL token, allocates supply (80% curve, 20% graduation liquidity),
-    /// and initializes the bonding curve for trading.
+    /// Creates a new SPL token with Metaplex metadata, allocates supply
+    /// (80% curve, 20% graduation liquidity), and initializes the bonding
+    /// curve for trading. The metadata update authority follows
+    /// `Config.metadata_policy`. The mint has no freeze authority and its
+    /// mint authority is revoked once the supply is minted. An optional
+    /// `initial_buy_lamports` buys for the creator in the same instruction,
+    /// within the opening anti-sniper caps and the holding cap. An optional
+    /// `trading_starts_at` holds off buys and sells until then, up to
+    /// `Config::max_trading_start_delay` ahead.
     /// 
     /// # Arguments
     /// * `ctx` - Create launch context
@@ -114,10 +270,28 @@ pub mod launchr {
         instructions::create_launch::create_launch(ctx, params)
     }
 
+    /// Create a new Token-2022 launch on the bonding curve
+    /// 
+    /// Same as `create_launch`, but the mint is created under Token-2022 with
+    /// the metadata-pointer and token-metadata extensions, so name, symbol
+    /// and uri live on the mint itself. Trading, graduation and refunds
+    /// accept either token program.
+    /// 
+    /// # Arguments
+    /// * `ctx` - Create Token-2022 launch context
+    /// * `params` - Launch parameters (name, symbol, uri, socials, fees)
+    pub fn create_launch_2022(ctx: Context<CreateLaunch2022>, params: CreateLaunchParams) -> Result<()> {
+        instructions::create_launch::create_launch_2022(ctx, params)
+    }
+
     /// Buy tokens from the bonding curve
     /// 
     /// Executes a buy order using SOL. The bonding curve uses constant product
     /// pricing (x * y = k). Includes slippage protection via min_tokens_out.
+    /// A buy that would overshoot the graduation threshold is filled only up
+    /// to it. During the launch's anti-sniper window, buys over the
+    /// per-transaction or per-wallet cap are rejected, as are buys that take
+    /// the wallet over the launch's `max_wallet_bps` holding cap.
     /// 
     /// # Arguments
     /// * `ctx` - Buy context
@@ -126,10 +300,35 @@ pub mod launchr {
         instructions::buy::buy(ctx, params)
     }
 
+    /// Buy tokens from the bonding curve with execution guards
+    /// 
+    /// Same as `buy`, but also rejects the trade once `deadline` has passed
+    /// or when its price impact exceeds `max_price_impact_bps`.
+    /// 
+    /// # Arguments
+    /// * `ctx` - Buy context
+    /// * `params` - Buy parameters plus optional deadline and price impact limit
+    pub fn buy_v2(ctx: Context<Buy>, params: BuyV2Params) -> Result<()> {
+        instructions::buy::buy_v2(ctx, params)
+    }
+
+    /// Buy an exact amount of tokens from the bonding curve
+    /// 
+    /// Charges the SOL needed for `tokens_out`, including the protocol and
+    /// creator fee legs, rounded up in the pool's favour.
+    /// 
+    /// # Arguments
+    /// * `ctx` - Buy context
+    /// * `params` - Exact token output and maximum SOL input
+    pub fn buy_exact_out(ctx: Context<Buy>, params: BuyExactOutParams) -> Result<()> {
+        instructions::buy::buy_exact_out(ctx, params)
+    }
+
     /// Sell tokens back to the bonding curve
     /// 
     /// Executes a sell order returning tokens for SOL. Includes slippage
-    /// protection via min_sol_out.
+    /// protection via min_sol_out. Opens a position for sellers who received
+    /// their tokens by transfer.
     /// 
     /// # Arguments
     /// * `ctx` - Sell context
@@ -138,6 +337,30 @@ pub mod launchr {
         instructions::sell::sell(ctx, params)
     }
 
+    /// Sell tokens back to the bonding curve with execution guards
+    /// 
+    /// Same as `sell`, but also rejects the trade once `deadline` has passed
+    /// or when its price impact exceeds `max_price_impact_bps`.
+    /// 
+    /// # Arguments
+    /// * `ctx` - Sell context
+    /// * `params` - Sell parameters plus optional deadline and price impact limit
+    pub fn sell_v2(ctx: Context<Sell>, params: SellV2Params) -> Result<()> {
+        instructions::sell::sell_v2(ctx, params)
+    }
+
+    /// Sell tokens for an exact amount of SOL
+    /// 
+    /// Spends the tokens needed for the seller to receive exactly `sol_out`
+    /// after fees, rounded up in the pool's favour.
+    /// 
+    /// # Arguments
+    /// * `ctx` - Sell context
+    /// * `params` - Exact SOL output and maximum token input
+    pub fn sell_exact_out(ctx: Context<Sell>, params: SellExactOutParams) -> Result<()> {
+        instructions::sell::sell_exact_out(ctx, params)
+    }
+
     /// Graduate a launch to Orbit Finance DLMM
     /// 
     /// Migrates a launch from the bonding curve to Orbit Finance concentrated
@@ -148,6 +371,7 @@ pub mod launchr {
     /// 2. Initializes all pool vaults (base, quote, fee vaults)
     /// 3. Creates bin array at the current price
     /// 4. Transfers all liquidity to Orbit vaults
+    /// 5. Locks token metadata still held by the launch authority
     /// 
     /// # Arguments
     /// * `ctx` - Graduate context
@@ -155,4 +379,107 @@ pub mod launchr {
     pub fn graduate(ctx: Context<Graduate>, params: GraduateParams) -> Result<()> {
         instructions::graduate::graduate(ctx, params)
     }
+
+    /// Withdraw protocol fees from the fee vault
+    /// 
+    /// Moves treasury fees collected on buys and sells out of the fee vault PDA.
+    /// Only callable by the config fee authority, or via multisig proposal in
+    /// multisig mode. The vault always keeps its rent-exempt minimum.
+    /// 
+    /// # Arguments
+    /// * `ctx` - Withdraw protocol fees context
+    /// * `params` - Withdrawal parameters (amount, None = all available)
+    pub fn withdraw_protocol_fees(
+        ctx: Context<WithdrawProtocolFees>,
+        params: WithdrawProtocolFeesParams,
+    ) -> Result<()> {
+        instructions::withdraw_fees::withdraw_protocol_fees(ctx, params)
+    }
+
+    /// Cancel a launch before anyone else has traded
+    /// 
+    /// Lets the creator retract a launch while only their own buys exist.
+    /// Burns all curve, graduation and creator-held tokens, refunds the curve
+    /// SOL and closes the vaults and launch account to the creator.
+    /// 
+    /// # Arguments
+    /// * `ctx` - Cancel launch context
+    pub fn cancel_launch(ctx: Context<CancelLaunch>) -> Result<()> {
+        instructions::cancel_launch::cancel_launch(ctx)
+    }
+
+    /// Bring a scheduled launch's trading start forward
+    /// 
+    /// The creator can open trading earlier than scheduled but never push
+    /// the start back. A start in the past opens trading immediately.
+    /// 
+    /// # Arguments
+    /// * `ctx` - Set trading start context
+    /// * `params` - New trading start timestamp
+    pub fn set_trading_start(ctx: Context<SetTradingStart>, params: SetTradingStartParams) -> Result<()> {
+        instructions::trading_start::set_trading_start(ctx, params)
+    }
+
+    /// Reconcile a position's holder status with its token account
+    /// 
+    /// Permissionless. Counts the position in `holder_count` if the owner's
+    /// associated token account holds tokens and uncounts it otherwise, so
+    /// transfers outside the curve don't leave the count stale. Also the
+    /// recount path for launches and positions from before holders were
+    /// tracked by balance.
+    /// 
+    /// # Arguments
+    /// * `ctx` - Reconcile position context
+    pub fn resync_holder(ctx: Context<ReconcilePosition>) -> Result<()> {
+        instructions::position::resync_holder(ctx)
+    }
+
+    /// Sync a position's token balance with its token account
+    /// 
+    /// Permissionless. Tokens received by transfer are added as zero-cost
+    /// lots; tokens sent away reduce the cost basis proportionally. Both are
+    /// tracked in the position's transferred-in and transferred-out totals.
+    /// 
+    /// # Arguments
+    /// * `ctx` - Reconcile position context
+    pub fn sync_position(ctx: Context<ReconcilePosition>) -> Result<()> {
+        instructions::position::sync_position(ctx)
+    }
+
+    /// Close a user position and reclaim its rent
+    /// 
+    /// Allowed once the position's token balance is zero or the launch has
+    /// graduated, but not during the anti-sniper window, whose per-wallet cap
+    /// counts the position's tokens bought. Emits the position's final stats,
+    /// including realized PnL, before the account is closed to the user.
+    /// 
+    /// # Arguments
+    /// * `ctx` - Close position context
+    pub fn close_position(ctx: Context<ClosePosition>) -> Result<()> {
+        instructions::position::close_position(ctx)
+    }
+
+    /// Put an expired launch into refund mode
+    /// 
+    /// Can be called by anyone once the launch's refund window has passed
+    /// without reaching the graduation threshold.
+    /// 
+    /// # Arguments
+    /// * `ctx` - Enable refunds context
+    pub fn enable_refunds(ctx: Context<EnableRefunds>) -> Result<()> {
+        instructions::refund::enable_refunds(ctx)
+    }
+
+    /// Redeem tokens from a refunding launch
+    /// 
+    /// Burns the holder's tokens and pays out a pro-rata share of the SOL
+    /// remaining in the curve vault. The holder's position is updated, and
+    /// opened if they only received tokens by transfer.
+    /// 
+    /// # Arguments
+    /// * `ctx` - Redeem refund context
+    /// * `params` - Refund parameters (token_amount)
+    pub fn redeem_refund(ctx: Context<RedeemRefund>, params: RedeemRefundParams) -> Result<()> {
+        instructions::refund::redeem_refund(ctx, params)
+    }
 }
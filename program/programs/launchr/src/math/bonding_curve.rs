@@ -66,8 +66,10 @@ pub fn calculate_buy(
     let creator_fee = (sol_in as u128 * creator_fee_bps as u128 / BPS_DENOMINATOR as u128) as u64;
     let protocol_fee = total_fee.saturating_sub(creator_fee); // Treasury portion
     
-    // SOL after fee deduction
-    let sol_in_after_fee = sol_in.saturating_sub(total_fee);
+    // SOL after fee deduction (both legs, in case the creator fee exceeds the total)
+    let sol_in_after_fee = sol_in
+        .saturating_sub(protocol_fee)
+        .saturating_sub(creator_fee);
     require!(sol_in_after_fee > 0, LaunchrError::TradeTooSmall);
     
     // Constant product: k = sol_reserve * token_reserve
@@ -149,8 +151,10 @@ pub fn calculate_sell(
     let creator_fee = (sol_out_before_fee as u128 * creator_fee_bps as u128 / BPS_DENOMINATOR as u128) as u64;
     let protocol_fee = total_fee.saturating_sub(creator_fee); // Treasury portion
     
-    // SOL out after fees
-    let sol_out = sol_out_before_fee.saturating_sub(total_fee);
+    // SOL out after fees (both legs, in case the creator fee exceeds the total)
+    let sol_out = sol_out_before_fee
+        .saturating_sub(protocol_fee)
+        .saturating_sub(creator_fee);
     require!(sol_out >= MIN_TRADE_AMOUNT, LaunchrError::TradeTooSmall);
     
     // Calculate price after swap
@@ -185,12 +189,23 @@ pub fn calculate_price(sol_reserve: u64, token_reserve: u64) -> u64 {
 }
 
 /// Calculate SOL needed for exact token output
+///
+/// Inverse of `calculate_buy`: the smallest `sol_in` (fees included) for which
+/// `calculate_buy` returns at least `tokens_out`. Both divisions round up, so
+/// any rounding favours the pool.
+///
+/// # Arguments
+/// * `tokens_out` - Exact amount of tokens wanted
+/// * `sol_reserve` - Current virtual SOL reserve
+/// * `token_reserve` - Current virtual token reserve
+/// * `protocol_fee_bps` - Total protocol fee in basis points
+/// * `creator_fee_bps` - Creator's share of protocol fee
 pub fn calculate_sol_for_tokens(
     tokens_out: u64,
     sol_reserve: u64,
     token_reserve: u64,
     protocol_fee_bps: u16,
-    _creator_fee_bps: u16,
+    creator_fee_bps: u16,
 ) -> Result<u64> {
     require!(tokens_out > 0 && tokens_out < token_reserve, LaunchrError::InvalidAmount);
     require!(sol_reserve > 0 && token_reserve > 0, LaunchrError::InvalidReserves);
@@ -202,17 +217,88 @@ pub fn calculate_sol_for_tokens(
     let new_token_reserve = token_reserve.saturating_sub(tokens_out);
     require!(new_token_reserve > 0, LaunchrError::InsufficientLiquidity);
     
-    // new_sol_reserve = k / new_token_reserve
-    let new_sol_reserve = (k / new_token_reserve as u128) as u64;
+    // new_sol_reserve = ceil(k / new_token_reserve)
+    let new_sol_reserve = k.div_ceil(new_token_reserve as u128);
     
     // sol_in_after_fee = new_sol_reserve - sol_reserve
-    let sol_in_after_fee = new_sol_reserve.saturating_sub(sol_reserve);
+    let sol_in_after_fee = u64::try_from(new_sol_reserve.saturating_sub(sol_reserve as u128))
+        .map_err(|_| error!(LaunchrError::MathOverflow))?;
     
-    // sol_in = sol_in_after_fee / (1 - fee_rate)
-    // Total fee is just protocol_fee_bps (creator fee comes from it, not added)
-    let sol_in = (sol_in_after_fee as u128 * BPS_DENOMINATOR as u128 / (BPS_DENOMINATOR - protocol_fee_bps as u64) as u128) as u64;
+    calculate_sol_for_net_input(sol_in_after_fee, protocol_fee_bps, creator_fee_bps)
+}
+
+/// Calculate the smallest SOL input whose after-fee amount is `net_sol`
+///
+/// The treasury and creator legs together take `max(protocol, creator)` bps,
+/// since the creator fee is carved out of the protocol fee. The after-fee
+/// amount grows by at most one lamport per lamport of input, so the result
+/// puts exactly `net_sol` into the curve.
+///
+/// # Arguments
+/// * `net_sol` - SOL that should reach the curve after fees
+/// * `protocol_fee_bps` - Total protocol fee in basis points
+/// * `creator_fee_bps` - Creator's share of protocol fee
+pub fn calculate_sol_for_net_input(
+    net_sol: u64,
+    protocol_fee_bps: u16,
+    creator_fee_bps: u16,
+) -> Result<u64> {
+    let fee_bps = protocol_fee_bps.max(creator_fee_bps) as u128;
+    require!(fee_bps < BPS_DENOMINATOR as u128, LaunchrError::InvalidConfig);
+    
+    let net_of = |gross: u128| gross - gross * fee_bps / BPS_DENOMINATOR as u128;
+    
+    // sol_in = ceil(net_sol / (1 - fee_rate)), then step down past fee rounding
+    let mut sol_in = (net_sol as u128 * BPS_DENOMINATOR as u128)
+        .div_ceil(BPS_DENOMINATOR as u128 - fee_bps);
+    while sol_in > 0 && net_of(sol_in - 1) >= net_sol as u128 {
+        sol_in -= 1;
+    }
     
-    Ok(sol_in.saturating_add(1)) // Add 1 for rounding
+    u64::try_from(sol_in).map_err(|_| error!(LaunchrError::MathOverflow))
+}
+
+/// Calculate tokens needed for exact SOL output (after fees)
+///
+/// Inverse of `calculate_sell`: the smallest `tokens_in` for which
+/// `calculate_sell` pays out at least `sol_out`. Both divisions round up, so
+/// any rounding favours the pool.
+///
+/// # Arguments
+/// * `sol_out` - Exact SOL the seller wants to receive (lamports, after fees)
+/// * `sol_reserve` - Current virtual SOL reserve
+/// * `token_reserve` - Current virtual token reserve
+/// * `protocol_fee_bps` - Total protocol fee in basis points
+/// * `creator_fee_bps` - Creator's share of protocol fee
+pub fn calculate_tokens_for_sol_out(
+    sol_out: u64,
+    sol_reserve: u64,
+    token_reserve: u64,
+    protocol_fee_bps: u16,
+    creator_fee_bps: u16,
+) -> Result<u64> {
+    require!(sol_out >= MIN_TRADE_AMOUNT, LaunchrError::TradeTooSmall);
+    require!(sol_reserve > 0 && token_reserve > 0, LaunchrError::InvalidReserves);
+    
+    // The treasury and creator legs together take max(protocol, creator) bps
+    let fee_bps = protocol_fee_bps.max(creator_fee_bps) as u128;
+    require!(fee_bps < BPS_DENOMINATOR as u128, LaunchrError::InvalidConfig);
+    
+    // sol_out_before_fee = ceil(sol_out / (1 - fee_rate))
+    let sol_out_before_fee = (sol_out as u128 * BPS_DENOMINATOR as u128)
+        .div_ceil(BPS_DENOMINATOR as u128 - fee_bps);
+    require!(sol_out_before_fee < sol_reserve as u128, LaunchrError::InsufficientLiquidity);
+    
+    // k = sol_reserve * token_reserve
+    let k = (sol_reserve as u128) * (token_reserve as u128);
+    
+    // new_token_reserve = ceil(k / (sol_reserve - sol_out_before_fee))
+    let new_sol_reserve = sol_reserve as u128 - sol_out_before_fee;
+    let new_token_reserve = k.div_ceil(new_sol_reserve);
+    
+    // tokens_in = new_token_reserve - token_reserve
+    let tokens_in = new_token_reserve.saturating_sub(token_reserve as u128);
+    u64::try_from(tokens_in).map_err(|_| error!(LaunchrError::MathOverflow))
 }
 
 /// Calculate tokens received for exact SOL input
@@ -266,6 +352,58 @@ pub enum LaunchrError {
     InvalidTreasury,
     #[msg("Insufficient SOL for graduation distribution")]
     InsufficientGraduationFunds,
+    #[msg("Launch has trades from other users")]
+    LaunchHasTrades,
+    #[msg("Launch has not expired")]
+    LaunchNotExpired,
+    #[msg("Refunds are not active for this launch")]
+    RefundsNotActive,
+    #[msg("No admin transfer is pending")]
+    NoPendingAdmin,
+    #[msg("Change must be queued through the config timelock")]
+    TimelockRequired,
+    #[msg("Config timelock has not elapsed")]
+    TimelockNotElapsed,
+    #[msg("Admin action requires an approved multisig proposal")]
+    MultisigRequired,
+    #[msg("Signer is not a multisig member")]
+    NotMultisigSigner,
+    #[msg("Proposal is executed, expired or stale")]
+    ProposalNotOpen,
+    #[msg("Proposal has not reached the approval threshold")]
+    ProposalNotApproved,
+    #[msg("Proposal action does not match this instruction")]
+    ProposalActionMismatch,
+    #[msg("Proposal is still open")]
+    ProposalStillOpen,
+    #[msg("Trading is paused for this launch")]
+    LaunchPaused,
+    #[msg("Launch has been delisted")]
+    LaunchDelisted,
+    #[msg("Buy would exceed the graduation threshold")]
+    GraduationThresholdExceeded,
+    #[msg("Trading has not started yet")]
+    TradingNotStarted,
+    #[msg("Trading start is further out than the config allows")]
+    TradingStartTooLate,
+    #[msg("Trading start can only be moved earlier")]
+    TradingStartNotEarlier,
+    #[msg("Buy exceeds the anti-sniper per-transaction cap")]
+    SniperBuyCapExceeded,
+    #[msg("Buy exceeds the anti-sniper per-wallet cap")]
+    SniperWalletCapExceeded,
+    #[msg("Buy would take the wallet over the launch's holding cap")]
+    WalletCapExceeded,
+    #[msg("Transaction deadline has passed")]
+    DeadlineExceeded,
+    #[msg("Price impact exceeds the allowed maximum")]
+    PriceImpactExceeded,
+    #[msg("Position still holds tokens")]
+    PositionNotEmpty,
+    #[msg("Positions can't be closed during the anti-sniper window")]
+    SniperWindowActive,
+    #[msg("Orbit pools need both mints on the same token program")]
+    OrbitTokenProgramMismatch,
 }
 
 #[cfg(test)]
@@ -351,4 +489,92 @@ mod tests {
         let max_deviation = k_before / 1000;
         assert!(deviation < max_deviation);
     }
+    
+    #[test]
+    fn test_sol_for_tokens_inverts_buy() {
+        for (protocol_fee, creator_fee) in [(100, 20), (100, 0), (0, 20), (250, 20)] {
+            for tokens_out in [1_000_000_000_000u64, 25_000_000_000_000_000, 500_000_000_000_000_000] {
+                let sol_in = calculate_sol_for_tokens(
+                    tokens_out,
+                    SOL_RESERVE,
+                    TOKEN_RESERVE,
+                    protocol_fee,
+                    creator_fee,
+                ).unwrap();
+                let result = calculate_buy(
+                    sol_in,
+                    SOL_RESERVE,
+                    TOKEN_RESERVE,
+                    protocol_fee,
+                    creator_fee,
+                ).unwrap();
+                
+                // Never short the buyer, and never overcharge beyond rounding
+                assert!(result.amount_out >= tokens_out);
+                let short = calculate_buy(
+                    sol_in - 2,
+                    SOL_RESERVE,
+                    TOKEN_RESERVE,
+                    protocol_fee,
+                    creator_fee,
+                ).unwrap();
+                assert!(short.amount_out < tokens_out);
+            }
+        }
+    }
+    
+    #[test]
+    fn test_sol_for_tokens_charges_creator_fee() {
+        let tokens_out = 25_000_000_000_000_000u64;
+        let without_creator = calculate_sol_for_tokens(tokens_out, SOL_RESERVE, TOKEN_RESERVE, 0, 0).unwrap();
+        let with_creator = calculate_sol_for_tokens(tokens_out, SOL_RESERVE, TOKEN_RESERVE, 0, 20).unwrap();
+        assert!(with_creator > without_creator);
+        
+        let result = calculate_buy(with_creator, SOL_RESERVE, TOKEN_RESERVE, 0, 20).unwrap();
+        assert!(result.creator_fee > 0);
+        assert!(result.amount_out >= tokens_out);
+    }
+    
+    #[test]
+    fn test_tokens_for_sol_out_inverts_sell() {
+        for (protocol_fee, creator_fee) in [(100, 20), (100, 0), (0, 20), (250, 20)] {
+            for sol_out in [MIN_TRADE_AMOUNT, 10_000_000, 900_000_000, 20_000_000_000] {
+                let tokens_in = calculate_tokens_for_sol_out(
+                    sol_out,
+                    SOL_RESERVE,
+                    TOKEN_RESERVE,
+                    protocol_fee,
+                    creator_fee,
+                ).unwrap();
+                let result = calculate_sell(
+                    tokens_in,
+                    SOL_RESERVE,
+                    TOKEN_RESERVE,
+                    protocol_fee,
+                    creator_fee,
+                ).unwrap();
+                
+                // Always pays at least the requested SOL, overshooting only by rounding
+                assert!(result.amount_out >= sol_out);
+                assert!(result.amount_out <= sol_out + 2);
+            }
+        }
+        
+        // Can't ask for the whole SOL reserve
+        assert!(calculate_tokens_for_sol_out(SOL_RESERVE, SOL_RESERVE, TOKEN_RESERVE, PROTOCOL_FEE, CREATOR_FEE).is_err());
+    }
+    
+    #[test]
+    fn test_sol_for_net_input_is_exact() {
+        for (protocol_fee, creator_fee) in [(100, 20), (100, 0), (0, 20), (333, 20)] {
+            for net_sol in [1_000_000u64, 123_456_789, 55_000_000_000] {
+                let sol_in = calculate_sol_for_net_input(net_sol, protocol_fee, creator_fee).unwrap();
+                let result = calculate_buy(sol_in, SOL_RESERVE, TOKEN_RESERVE, protocol_fee, creator_fee).unwrap();
+                assert_eq!(sol_in - result.protocol_fee - result.creator_fee, net_sol);
+                
+                let short = calculate_buy(sol_in - 1, SOL_RESERVE, TOKEN_RESERVE, protocol_fee, creator_fee).unwrap();
+                assert!(sol_in - 1 - short.protocol_fee - short.creator_fee < net_sol);
+            }
+        }
+    }
 }
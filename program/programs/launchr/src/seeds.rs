@@ -33,6 +33,18 @@ pub const FEE_VAULT_SEED: &[u8] = b"fee_vault";
 /// Graduation reserve vault - [GRADUATION_VAULT_SEED, launch]
 pub const GRADUATION_VAULT_SEED: &[u8] = b"graduation_vault";
 
+/// Queued (timelocked) config change - [PENDING_CONFIG_SEED, config]
+pub const PENDING_CONFIG_SEED: &[u8] = b"pending_config";
+
+/// Delegated role assignments - [ROLES_SEED, config]
+pub const ROLES_SEED: &[u8] = b"roles";
+
+/// Admin multisig - [MULTISIG_SEED, config]
+pub const MULTISIG_SEED: &[u8] = b"multisig";
+
+/// Multisig proposal - [PROPOSAL_SEED, multisig, index (u64 LE)]
+pub const PROPOSAL_SEED: &[u8] = b"proposal";
+
 // ============================================================================
 // ORBIT FINANCE SEEDS (for graduation CPI)
 // ============================================================================
@@ -57,6 +69,13 @@ pub const ORBIT_POSITION_BIN_SEED: &[u8] = b"position_bin";
 /// Orbit bin array seed - [ORBIT_BIN_ARRAY_SEED, pool, lower_bin_index]
 pub const ORBIT_BIN_ARRAY_SEED: &[u8] = b"bin_array";
 
+// ============================================================================
+// TOKEN METADATA SEEDS (for Metaplex CPI)
+// ============================================================================
+
+/// Metaplex metadata seed - [METADATA_SEED, metadata_program, mint]
+pub const METADATA_SEED: &[u8] = b"metadata";
+
 // ============================================================================
 // LAUNCHR PDA DERIVATION HELPERS
 // ============================================================================
@@ -108,6 +127,29 @@ pub fn derive_graduation_vault(launch: &Pubkey, program_id: &Pubkey) -> (Pubkey,
     Pubkey::find_program_address(&[GRADUATION_VAULT_SEED, launch.as_ref()], program_id)
 }
 
+/// Derive the queued config change PDA
+pub fn derive_pending_config(config: &Pubkey, program_id: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[PENDING_CONFIG_SEED, config.as_ref()], program_id)
+}
+
+/// Derive the roles PDA
+pub fn derive_roles(config: &Pubkey, program_id: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[ROLES_SEED, config.as_ref()], program_id)
+}
+
+/// Derive the multisig PDA
+pub fn derive_multisig(config: &Pubkey, program_id: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[MULTISIG_SEED, config.as_ref()], program_id)
+}
+
+/// Derive a multisig proposal PDA
+pub fn derive_proposal(multisig: &Pubkey, index: u64, program_id: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[PROPOSAL_SEED, multisig.as_ref(), &index.to_le_bytes()],
+        program_id,
+    )
+}
+
 // ============================================================================
 // ORBIT FINANCE PDA DERIVATION HELPERS
 // ============================================================================
@@ -184,6 +226,19 @@ pub fn derive_orbit_bin_array(pool: &Pubkey, lower_bin_index: i32) -> (Pubkey, u
     )
 }
 
+// ============================================================================
+// TOKEN METADATA PDA DERIVATION HELPERS
+// ============================================================================
+
+/// Derive the Metaplex metadata account for a mint
+pub fn derive_metadata(mint: &Pubkey) -> (Pubkey, u8) {
+    let metadata_program = anchor_spl::metadata::ID;
+    Pubkey::find_program_address(
+        &[METADATA_SEED, metadata_program.as_ref(), mint.as_ref()],
+        &metadata_program,
+    )
+}
+
 // ============================================================================
 // UTILITY FUNCTIONS
 // ============================================================================
@@ -241,4 +296,11 @@ mod tests {
         assert!(is_canonical_order(&small, &large));
         assert!(!is_canonical_order(&large, &small));
     }
+
+    #[test]
+    fn test_metadata_pda_matches_metaplex() {
+        let mint = Pubkey::new_unique();
+        let expected = anchor_spl::metadata::mpl_token_metadata::accounts::Metadata::find_pda(&mint);
+        assert_eq!(derive_metadata(&mint), expected);
+    }
 }
@@ -6,6 +6,7 @@ use anchor_lang::prelude::*;
 
 /// Global configuration account for the Launchr protocol
 #[account]
+#[derive(Default)]
 pub struct Config {
     /// Admin authority - can update config and pause launches
     pub admin: Pubkey,
@@ -54,8 +55,47 @@ pub struct Config {
     /// Bump seed for PDA
     pub bump: u8,
     
+    /// Seconds after creation before a non-graduated launch can enter refund mode (0 = never)
+    pub refund_window_secs: u32,
+    
+    /// Proposed new admin awaiting acceptance (default = none)
+    pub pending_admin: Pubkey,
+    
+    /// Delay in seconds before queued config changes can execute (0 = no timelock)
+    pub config_timelock_secs: u32,
+    
+    /// Whether admin actions require an approved multisig proposal
+    pub multisig_enabled: bool,
+    
+    /// Share of the graduation threshold paid to the creator at graduation (BPS)
+    pub graduation_creator_reward_bps: u16,
+    
+    /// Share of the graduation threshold paid to the treasury at graduation (BPS)
+    pub graduation_treasury_fee_bps: u16,
+    
+    /// Who controls token metadata of new launches
+    pub metadata_policy: MetadataPolicy,
+    
+    /// Furthest a creator may schedule the start of trading after creation (seconds)
+    pub max_trading_start_delay_secs: u32,
+    
+    /// Anti-sniper window after trading starts, for new launches (seconds, 0 = disabled)
+    pub sniper_window_secs: u32,
+    
+    /// Per-transaction buy cap at the start of the anti-sniper window (BPS of the graduation threshold)
+    pub sniper_max_buy_bps: u16,
+    
+    /// Per-wallet token cap at the start of the anti-sniper window (BPS of total supply)
+    pub sniper_max_wallet_bps: u16,
+    
+    /// Whether the graduation payout BPS have been set (false on configs created before they existed)
+    pub graduation_payouts_set: bool,
+    
+    /// Whether the max trading start delay has been set (false on configs created before it existed)
+    pub trading_start_delay_set: bool,
+    
     /// Reserved for future use
-    pub _reserved: [u8; 64],
+    pub _reserved: [u8; 4],
 }
 
 impl Config {
@@ -76,7 +116,20 @@ impl Config {
         16 +    // total_volume_lamports
         8 +     // total_fees_collected
         1 +     // bump
-        64;     // reserved
+        4 +     // refund_window_secs
+        32 +    // pending_admin
+        4 +     // config_timelock_secs
+        1 +     // multisig_enabled
+        2 +     // graduation_creator_reward_bps
+        2 +     // graduation_treasury_fee_bps
+        1 +     // metadata_policy
+        4 +     // max_trading_start_delay_secs
+        4 +     // sniper_window_secs
+        2 +     // sniper_max_buy_bps
+        2 +     // sniper_max_wallet_bps
+        1 +     // graduation_payouts_set
+        1 +     // trading_start_delay_set
+        4;      // reserved
     
     /// Initialize a new config
     #[allow(clippy::too_many_arguments)]
@@ -107,9 +160,84 @@ impl Config {
         self.total_volume_lamports = 0;
         self.total_fees_collected = 0;
         self.bump = bump;
+        self.refund_window_secs = 0;
+        self.pending_admin = Pubkey::default();
+        self.config_timelock_secs = 0;
+        self.multisig_enabled = false;
+        self.graduation_creator_reward_bps = defaults::GRADUATION_CREATOR_REWARD_BPS;
+        self.graduation_treasury_fee_bps = defaults::GRADUATION_TREASURY_FEE_BPS;
+        self.graduation_payouts_set = true;
+        self.metadata_policy = MetadataPolicy::default();
+        self.max_trading_start_delay_secs = defaults::MAX_TRADING_START_DELAY_SECS;
+        self.trading_start_delay_set = true;
+        self.sniper_window_secs = 0;
+        self.sniper_max_buy_bps = defaults::SNIPER_MAX_BUY_BPS;
+        self.sniper_max_wallet_bps = defaults::SNIPER_MAX_WALLET_BPS;
         Ok(())
     }
     
+    /// Check if an admin transfer is awaiting acceptance
+    pub fn has_pending_admin(&self) -> bool {
+        self.pending_admin != Pubkey::default()
+    }
+    
+    /// Check if `key` can act as admin directly
+    ///
+    /// Always false in multisig mode - admin actions then need an approved proposal.
+    pub fn is_admin(&self, key: &Pubkey) -> bool {
+        !self.multisig_enabled && self.admin == *key
+    }
+    
+    /// Graduation payout shares (creator reward, treasury fee) in BPS
+    ///
+    /// Configs created before the payouts were configurable read zeros here;
+    /// their launches keep the fixed `graduation` payouts until an update sets
+    /// the shares, which then start from these defaults.
+    pub fn graduation_payout_bps(&self) -> (u16, u16) {
+        if self.graduation_payouts_set {
+            (self.graduation_creator_reward_bps, self.graduation_treasury_fee_bps)
+        } else {
+            (defaults::GRADUATION_CREATOR_REWARD_BPS, defaults::GRADUATION_TREASURY_FEE_BPS)
+        }
+    }
+    
+    /// Furthest a creator may schedule the start of trading (seconds)
+    ///
+    /// Configs created before the delay was configurable read zero here and
+    /// allow the default until an update sets it.
+    pub fn max_trading_start_delay(&self) -> u32 {
+        if self.trading_start_delay_set {
+            self.max_trading_start_delay_secs
+        } else {
+            defaults::MAX_TRADING_START_DELAY_SECS
+        }
+    }
+    
+    /// Snapshot of the admin-settable values
+    pub fn settings(&self) -> ConfigSettings {
+        let (graduation_creator_reward_bps, graduation_treasury_fee_bps) = self.graduation_payout_bps();
+        ConfigSettings {
+            fee_authority: self.fee_authority,
+            protocol_fee_bps: self.protocol_fee_bps,
+            graduation_threshold: self.graduation_threshold,
+            quote_mint: self.quote_mint,
+            orbit_program_id: self.orbit_program_id,
+            default_bin_step_bps: self.default_bin_step_bps,
+            default_base_fee_bps: self.default_base_fee_bps,
+            launches_paused: self.launches_paused,
+            trading_paused: self.trading_paused,
+            refund_window_secs: self.refund_window_secs,
+            config_timelock_secs: self.config_timelock_secs,
+            graduation_creator_reward_bps,
+            graduation_treasury_fee_bps,
+            metadata_policy: self.metadata_policy,
+            max_trading_start_delay_secs: self.max_trading_start_delay(),
+            sniper_window_secs: self.sniper_window_secs,
+            sniper_max_buy_bps: self.sniper_max_buy_bps,
+            sniper_max_wallet_bps: self.sniper_max_wallet_bps,
+        }
+    }
+    
     /// Record a new launch
     pub fn record_launch(&mut self) {
         self.total_launches = self.total_launches.saturating_add(1);
@@ -127,6 +255,74 @@ impl Config {
     }
 }
 
+/// Update authority policy for launch token metadata
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum MetadataPolicy {
+    /// Launch authority PDA holds update authority; metadata is locked at graduation
+    #[default]
+    LockAtGraduation,
+    /// Creator holds update authority (never locked by the program)
+    Creator,
+    /// Metadata is immutable from creation
+    Immutable,
+}
+
+/// Admin-settable configuration values, reported before and after each change
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub struct ConfigSettings {
+    pub fee_authority: Pubkey,
+    pub protocol_fee_bps: u16,
+    pub graduation_threshold: u64,
+    pub quote_mint: Pubkey,
+    pub orbit_program_id: Pubkey,
+    pub default_bin_step_bps: u16,
+    pub default_base_fee_bps: u16,
+    pub launches_paused: bool,
+    pub trading_paused: bool,
+    pub refund_window_secs: u32,
+    pub config_timelock_secs: u32,
+    pub graduation_creator_reward_bps: u16,
+    pub graduation_treasury_fee_bps: u16,
+    pub metadata_policy: MetadataPolicy,
+    pub max_trading_start_delay_secs: u32,
+    pub sniper_window_secs: u32,
+    pub sniper_max_buy_bps: u16,
+    pub sniper_max_wallet_bps: u16,
+}
+
+/// Validation limits for configuration values
+pub mod limits {
+    /// Maximum protocol fee: 10% (1000 basis points)
+    pub const MAX_PROTOCOL_FEE_BPS: u16 = 1000;
+    
+    /// Minimum Orbit bin step: 1 BPS
+    pub const MIN_BIN_STEP_BPS: u16 = 1;
+    
+    /// Maximum Orbit bin step: 500 BPS (5%)
+    pub const MAX_BIN_STEP_BPS: u16 = 500;
+    
+    /// Maximum Orbit base fee: 10% (1000 basis points)
+    pub const MAX_BASE_FEE_BPS: u16 = 1000;
+    
+    /// Maximum config timelock: 30 days
+    pub const MAX_CONFIG_TIMELOCK_SECS: u32 = 30 * 24 * 60 * 60;
+    
+    /// Maximum graduation creator reward: 10% of the threshold
+    pub const MAX_GRADUATION_CREATOR_REWARD_BPS: u16 = 1000;
+    
+    /// Maximum graduation treasury fee: 10% of the threshold
+    pub const MAX_GRADUATION_TREASURY_FEE_BPS: u16 = 1000;
+    
+    /// Maximum trading start delay: 30 days
+    pub const MAX_TRADING_START_DELAY_SECS: u32 = 30 * 24 * 60 * 60;
+    
+    /// Maximum anti-sniper window: 24 hours
+    pub const MAX_SNIPER_WINDOW_SECS: u32 = 24 * 60 * 60;
+    
+    /// Maximum anti-sniper cap: 100% (no cap)
+    pub const MAX_SNIPER_CAP_BPS: u16 = 10_000;
+}
+
 /// Default configuration values
 pub mod defaults {
     /// Default protocol fee: 1% (100 basis points)
@@ -141,29 +337,43 @@ pub mod defaults {
     /// Default Orbit base fee: 30 BPS (0.30%)
     pub const BASE_FEE_BPS: u16 = 30;
     
+    /// Default graduation creator reward: 2.35% (1.9975 SOL of 85 SOL)
+    pub const GRADUATION_CREATOR_REWARD_BPS: u16 = 235;
+    
+    /// Default graduation treasury fee: 3.53% (3.0005 SOL of 85 SOL)
+    pub const GRADUATION_TREASURY_FEE_BPS: u16 = 353;
+    
+    /// Default maximum trading start delay: 7 days
+    pub const MAX_TRADING_START_DELAY_SECS: u32 = 7 * 24 * 60 * 60;
+    
+    /// Default anti-sniper buy cap: 1% of the threshold (0.85 SOL of 85 SOL)
+    pub const SNIPER_MAX_BUY_BPS: u16 = 100;
+    
+    /// Default anti-sniper wallet cap: 1% of total supply
+    pub const SNIPER_MAX_WALLET_BPS: u16 = 100;
+    
     /// WSOL mint address
     pub const WSOL_MINT: &str = "So11111111111111111111111111111111111111112";
 }
 
-impl Default for Config {
-    fn default() -> Self {
-        Self {
-            admin: Pubkey::default(),
-            fee_authority: Pubkey::default(),
-            protocol_fee_bps: 0,
-            graduation_threshold: 0,
-            quote_mint: Pubkey::default(),
-            orbit_program_id: Pubkey::default(),
-            default_bin_step_bps: 0,
-            default_base_fee_bps: 0,
-            launches_paused: false,
-            trading_paused: false,
-            total_launches: 0,
-            total_graduations: 0,
-            total_volume_lamports: 0,
-            total_fees_collected: 0,
-            bump: 0,
-            _reserved: [0u8; 64],
-        }
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pre_upgrade_config_allows_default_trading_start_delay() {
+        // Configs created before the delay existed read zeros in place of `_reserved`
+        let mut config = Config::default();
+        assert_eq!(config.max_trading_start_delay(), defaults::MAX_TRADING_START_DELAY_SECS);
+        assert_eq!(
+            config.settings().max_trading_start_delay_secs,
+            defaults::MAX_TRADING_START_DELAY_SECS
+        );
+
+        // Once set, zero is a real bound: trading has to open at creation
+        config.trading_start_delay_set = true;
+        assert_eq!(config.max_trading_start_delay(), 0);
+        config.max_trading_start_delay_secs = 3_600;
+        assert_eq!(config.max_trading_start_delay(), 3_600);
     }
 }
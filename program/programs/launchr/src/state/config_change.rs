@@ -0,0 +1,148 @@
+//! Launchr - Queued Config Change State
+//! 
+//! Timelocked configuration changes awaiting execution.
+
+use anchor_lang::prelude::*;
+use crate::instructions::UpdateConfigParams;
+use crate::state::Config;
+
+/// A config change queued behind the config timelock
+#[account]
+#[derive(Default)]
+pub struct PendingConfigChange {
+    /// Config this change applies to
+    pub config: Pubkey,
+    
+    /// Account that queued the change (receives rent on close)
+    pub proposer: Pubkey,
+    
+    /// Proposed changes
+    pub params: UpdateConfigParams,
+    
+    /// Unix timestamp when the change was queued
+    pub queued_at: i64,
+    
+    /// Unix timestamp after which the change can execute
+    pub eta: i64,
+    
+    /// Bump seed
+    pub bump: u8,
+}
+
+impl PendingConfigChange {
+    /// Account space calculation
+    pub const LEN: usize = 8 +  // discriminator
+        32 +    // config
+        32 +    // proposer
+        UpdateConfigParams::MAX_SIZE + // params
+        8 +     // queued_at
+        8 +     // eta
+        1;      // bump
+    
+    /// Check if the timelock has elapsed
+    pub fn is_ready(&self, now: i64) -> bool {
+        now >= self.eta
+    }
+    
+    /// Check if `key` can withdraw the change without admin authorization
+    ///
+    /// Only outside multisig mode: there the proposer is just the member who
+    /// executed the queue proposal, and one member can't undo an approved change.
+    pub fn proposer_may_cancel(&self, config: &Config, key: &Pubkey) -> bool {
+        !config.multisig_enabled && self.proposer == *key
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::{MetadataPolicy, Role, Roles};
+
+    #[test]
+    fn test_params_max_size() {
+        let params = UpdateConfigParams {
+            new_fee_authority: Some(Pubkey::new_unique()),
+            new_protocol_fee_bps: Some(100),
+            new_graduation_threshold: Some(85_000_000_000),
+            launches_paused: Some(true),
+            trading_paused: Some(true),
+            new_refund_window_secs: Some(86_400),
+            new_default_bin_step_bps: Some(25),
+            new_default_base_fee_bps: Some(30),
+            new_orbit_program_id: Some(Pubkey::new_unique()),
+            new_quote_mint: Some(Pubkey::new_unique()),
+            new_config_timelock_secs: Some(3_600),
+            new_graduation_creator_reward_bps: Some(235),
+            new_graduation_treasury_fee_bps: Some(353),
+            new_metadata_policy: Some(MetadataPolicy::Creator),
+            new_max_trading_start_delay_secs: Some(604_800),
+            new_sniper_window_secs: Some(300),
+            new_sniper_max_buy_bps: Some(100),
+            new_sniper_max_wallet_bps: Some(100),
+        };
+
+        assert_eq!(params.try_to_vec().unwrap().len(), UpdateConfigParams::MAX_SIZE);
+    }
+
+    #[test]
+    fn test_pause_flags_skip_timelock() {
+        let pause_only = UpdateConfigParams {
+            launches_paused: Some(true),
+            trading_paused: Some(true),
+            ..Default::default()
+        };
+        assert!(!pause_only.has_timelocked_changes());
+
+        let fee_change = UpdateConfigParams {
+            new_protocol_fee_bps: Some(1000),
+            ..pause_only
+        };
+        assert!(fee_change.has_timelocked_changes());
+    }
+
+    #[test]
+    fn test_roles_need_proposal_in_multisig_mode() {
+        let mut config = Config {
+            admin: Pubkey::new_unique(),
+            ..Default::default()
+        };
+        let (pauser, fee_manager, parameter_manager) =
+            (Pubkey::new_unique(), Pubkey::new_unique(), Pubkey::new_unique());
+        let mut roles = Roles::default();
+        roles.set_holder(Role::Pauser, pauser);
+        roles.set_holder(Role::FeeManager, fee_manager);
+        roles.set_holder(Role::ParameterManager, parameter_manager);
+        let roles = Some(&roles);
+
+        let pause = UpdateConfigParams { trading_paused: Some(true), ..Default::default() };
+        let fee = UpdateConfigParams { new_protocol_fee_bps: Some(50), ..Default::default() };
+        let parameter = UpdateConfigParams { new_orbit_program_id: Some(Pubkey::new_unique()), ..Default::default() };
+        let fee_authority = UpdateConfigParams { new_fee_authority: Some(fee_manager), ..Default::default() };
+
+        assert!(pause.covered_by_roles(&config, roles, &pauser));
+        assert!(fee.covered_by_roles(&config, roles, &fee_manager));
+        assert!(parameter.covered_by_roles(&config, roles, &parameter_manager));
+        // Redirecting the treasury is never delegated
+        assert!(!fee_authority.covered_by_roles(&config, roles, &fee_manager));
+
+        config.multisig_enabled = true;
+        assert!(pause.covered_by_roles(&config, roles, &pauser));
+        assert!(!fee.covered_by_roles(&config, roles, &fee_manager));
+        assert!(!parameter.covered_by_roles(&config, roles, &parameter_manager));
+    }
+
+    #[test]
+    fn test_proposer_cancel_needs_proposal_in_multisig_mode() {
+        let proposer = Pubkey::new_unique();
+        let pending_change = PendingConfigChange {
+            proposer,
+            ..Default::default()
+        };
+        let mut config = Config::default();
+        assert!(pending_change.proposer_may_cancel(&config, &proposer));
+        assert!(!pending_change.proposer_may_cancel(&config, &Pubkey::new_unique()));
+
+        config.multisig_enabled = true;
+        assert!(!pending_change.proposer_may_cancel(&config, &proposer));
+    }
+}
@@ -3,6 +3,7 @@
 //! State management for individual token launches on the bonding curve.
 
 use anchor_lang::prelude::*;
+use crate::state::UserPosition;
 
 /// Status of a token launch
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug, Default)]
@@ -16,6 +17,8 @@ pub enum LaunchStatus {
     Graduated,
     /// Launch was cancelled by creator
     Cancelled,
+    /// Launch expired without graduating - holders can redeem curve SOL
+    Refunding,
 }
 
 /// Token launch account - represents a single token on the bonding curve
@@ -82,7 +85,7 @@ pub struct Launch {
     /// Total number of trades
     pub trade_count: u64,
     
-    /// Number of unique holders (approximate)
+    /// Number of positions currently holding tokens
     pub holder_count: u32,
     
     // ========== Orbit Integration ==========
@@ -123,8 +126,54 @@ pub struct Launch {
     /// Launch authority bump
     pub authority_bump: u8,
     
+    // ========== Refunds ==========
+    
+    /// Refund window snapshotted from config at creation (0 = never expires)
+    pub refund_window_secs: u32,
+    
+    // ========== Moderation ==========
+    
+    /// Moderation flags (see `launch_flags`)
+    pub flags: u8,
+    
+    // ========== Graduation Payouts ==========
+    
+    /// Creator reward as a share of the graduation threshold, snapshotted from config (BPS)
+    pub graduation_creator_reward_bps: u16,
+    
+    /// Treasury fee as a share of the graduation threshold, snapshotted from config (BPS)
+    pub graduation_treasury_fee_bps: u16,
+    
+    // ========== Schedule ==========
+    
+    /// Unix timestamp from which buys and sells are accepted
+    pub trading_starts_at: i64,
+    
+    // ========== Anti-Sniper ==========
+    
+    /// Seconds after the trading start during which buys are capped (0 = no window)
+    pub sniper_window_secs: u32,
+    
+    /// Per-transaction buy cap at the trading start (BPS of the graduation threshold, 0 = none)
+    pub sniper_max_buy_bps: u16,
+    
+    /// Per-wallet cap on tokens bought at the trading start (BPS of total supply, 0 = none)
+    pub sniper_max_wallet_bps: u16,
+    
+    // ========== Holding Cap ==========
+    
+    /// Most any wallet may hold while on the curve (BPS of total supply, 0 = no cap)
+    pub max_wallet_bps: u16,
+    
+    /// Whether the graduation payout BPS were snapshotted (false on launches created before they existed)
+    pub graduation_payouts_set: bool,
+    
+    /// Whether `holder_count` follows holder balances (false on launches created
+    /// before; their count starts with a placeholder for the creator)
+    pub holders_tracked: bool,
+    
     /// Reserved for future use
-    pub _reserved: [u8; 32],
+    pub _reserved: [u8; 3],
 }
 
 impl Launch {
@@ -158,11 +207,42 @@ impl Launch {
         64 +    // website
         1 +     // bump
         1 +     // authority_bump
-        32;     // reserved
+        4 +     // refund_window_secs
+        1 +     // flags
+        2 +     // graduation_creator_reward_bps
+        2 +     // graduation_treasury_fee_bps
+        8 +     // trading_starts_at
+        4 +     // sniper_window_secs
+        2 +     // sniper_max_buy_bps
+        2 +     // sniper_max_wallet_bps
+        2 +     // max_wallet_bps
+        1 +     // graduation_payouts_set
+        1 +     // holders_tracked
+        3;      // reserved
+    
+    /// Check if launch is active and its trading start has passed
+    pub fn is_tradeable(&self, now: i64) -> bool {
+        self.status == LaunchStatus::Active && self.trading_started(now)
+    }
     
-    /// Check if launch is active and tradeable
-    pub fn is_tradeable(&self) -> bool {
-        self.status == LaunchStatus::Active
+    /// Check if the scheduled trading start has passed
+    pub fn trading_started(&self, now: i64) -> bool {
+        now >= self.trading_starts_at
+    }
+    
+    /// Check if trading has been paused on this launch by a moderator
+    pub fn is_paused(&self) -> bool {
+        self.flags & launch_flags::TRADING_PAUSED != 0
+    }
+    
+    /// Check if the launch has been delisted
+    pub fn is_delisted(&self) -> bool {
+        self.flags & launch_flags::DELISTED != 0
+    }
+    
+    /// Check if the launch should be hidden from listings
+    pub fn is_hidden(&self) -> bool {
+        self.flags & launch_flags::HIDDEN != 0
     }
     
     /// Check if launch can graduate
@@ -175,6 +255,50 @@ impl Launch {
         self.real_sol_reserve >= self.graduation_threshold
     }
     
+    /// SOL still needed in the curve to reach the graduation threshold
+    pub fn sol_to_threshold(&self) -> u64 {
+        self.graduation_threshold.saturating_sub(self.real_sol_reserve)
+    }
+    
+    /// Anti-sniper cap (BPS) in effect at `now` for a starting cap of `base_bps`
+    ///
+    /// Relaxes linearly from `base_bps` at the trading start to 100% at the end
+    /// of the window. `None` once the window is over or when the cap is disabled.
+    pub fn sniper_cap_bps(&self, base_bps: u16, now: i64) -> Option<u64> {
+        if base_bps == 0 || self.sniper_window_secs == 0 {
+            return None;
+        }
+        let elapsed = now.saturating_sub(self.trading_starts_at).max(0) as u64;
+        let window = self.sniper_window_secs as u64;
+        if elapsed >= window {
+            return None;
+        }
+        let base = (base_bps as u64).min(10_000);
+        Some(base + (10_000 - base) * elapsed / window)
+    }
+    
+    /// Largest buy (lamports, fees included) allowed in one transaction at `now`
+    pub fn sniper_max_buy_lamports(&self, now: i64) -> Option<u64> {
+        self.sniper_cap_bps(self.sniper_max_buy_bps, now)
+            .map(|bps| ((self.graduation_threshold as u128 * bps as u128) / 10_000) as u64)
+    }
+    
+    /// Most tokens a single wallet may have bought at `now`
+    pub fn sniper_max_wallet_tokens(&self, now: i64) -> Option<u64> {
+        self.sniper_cap_bps(self.sniper_max_wallet_bps, now)
+            .map(|bps| ((self.total_supply as u128 * bps as u128) / 10_000) as u64)
+    }
+    
+    /// Most tokens a single wallet may hold, if capped
+    ///
+    /// The cap only applies on the curve and lifts once the launch graduates.
+    pub fn max_wallet_tokens(&self) -> Option<u64> {
+        if self.max_wallet_bps == 0 || self.status == LaunchStatus::Graduated {
+            return None;
+        }
+        Some(((self.total_supply as u128 * self.max_wallet_bps as u128) / 10_000) as u64)
+    }
+    
     /// Get current price in lamports per token (scaled by 1e9)
     pub fn current_price(&self) -> u64 {
         if self.virtual_token_reserve == 0 {
@@ -209,6 +333,40 @@ impl Launch {
         }
     }
     
+    /// Count or uncount a position as a holder
+    ///
+    /// Only changes `holder_count` when the position's holder status flips,
+    /// so repeated calls with the same status are no-ops.
+    ///
+    /// Accounts from before holders were tracked by balance are migrated on
+    /// first use: the launch drops the creator placeholder from its count, and
+    /// a position that has traded was counted once by its first buy.
+    pub fn set_holder(&mut self, position: &mut UserPosition, holds: bool) {
+        if !self.holders_tracked {
+            self.holders_tracked = true;
+            self.holder_count = self.holder_count.saturating_sub(1);
+        }
+        if !position.holder_tracked {
+            position.holder_tracked = true;
+            position.is_holder = !position.is_new();
+        }
+        if position.is_holder == holds {
+            return;
+        }
+        position.is_holder = holds;
+        self.holder_count = if holds {
+            self.holder_count.saturating_add(1)
+        } else {
+            self.holder_count.saturating_sub(1)
+        };
+    }
+    
+    /// Count a position as a holder iff its tracked balance is non-zero
+    pub fn update_holder(&mut self, position: &mut UserPosition) {
+        let holds = position.token_balance > 0;
+        self.set_holder(position, holds);
+    }
+    
     /// Record a sell transaction
     /// `sol_user_payout` is the SOL sent to the user (after fees)
     /// `total_sol_removed` is the total SOL leaving the vault (payout + all fees)
@@ -224,6 +382,68 @@ impl Launch {
         self.virtual_token_reserve = self.virtual_token_reserve.saturating_add(tokens_in);
     }
     
+    /// Unix timestamp after which the launch can enter refund mode
+    ///
+    /// The window runs from the trading start, so a scheduled launch gets its full window.
+    pub fn refund_expiry(&self) -> Option<i64> {
+        if self.refund_window_secs == 0 {
+            return None;
+        }
+        let start = self.created_at.max(self.trading_starts_at);
+        Some(start.saturating_add(self.refund_window_secs as i64))
+    }
+    
+    /// Check if the launch expired without reaching its graduation threshold
+    pub fn can_enable_refunds(&self, now: i64) -> bool {
+        self.status == LaunchStatus::Active
+            && !self.threshold_reached()
+            && self.refund_expiry().is_some_and(|expiry| now >= expiry)
+    }
+    
+    /// Pro-rata share of the curve SOL for `tokens` of circulating supply
+    ///
+    /// Rounds down, so the last holder to redeem always finds enough SOL left.
+    pub fn refund_amount(&self, tokens: u64) -> u64 {
+        if self.tokens_sold == 0 {
+            return 0;
+        }
+        let tokens = tokens.min(self.tokens_sold);
+        ((self.real_sol_reserve as u128 * tokens as u128) / self.tokens_sold as u128) as u64
+    }
+    
+    /// SOL actually paid for redeeming `tokens`, given the curve vault balance
+    ///
+    /// The vault's rent-exempt minimum stays out of the refundable pool, so a
+    /// partial redemption never leaves the vault with a non-zero balance below
+    /// rent exemption (which would make every later transfer out of it fail).
+    pub fn refund_payout(&self, tokens: u64, vault_lamports: u64, rent_minimum: u64) -> u64 {
+        self.refund_amount(tokens)
+            .min(vault_lamports.saturating_sub(rent_minimum))
+    }
+    
+    /// Record a refund redemption
+    pub fn record_refund(&mut self, tokens_burned: u64, sol_refunded: u64) {
+        self.tokens_sold = self.tokens_sold.saturating_sub(tokens_burned);
+        self.real_sol_reserve = self.real_sol_reserve.saturating_sub(sol_refunded);
+    }
+    
+    /// Creator reward and treasury fee (lamports) paid out of the curve at graduation
+    ///
+    /// Both are shares of `graduation_threshold`; whatever else is in the curve vault goes to LP.
+    /// Launches without snapshotted shares keep the fixed amounts in `graduation`.
+    pub fn graduation_payouts(&self) -> (u64, u64) {
+        if !self.graduation_payouts_set {
+            return (graduation::CREATOR_REWARD_LAMPORTS, graduation::TREASURY_FEE_LAMPORTS);
+        }
+        let share = |bps: u16| {
+            ((self.graduation_threshold as u128 * bps as u128) / 10_000) as u64
+        };
+        (
+            share(self.graduation_creator_reward_bps),
+            share(self.graduation_treasury_fee_bps),
+        )
+    }
+    
     /// Mark as graduated
     pub fn graduate(&mut self, orbit_pool: Pubkey, timestamp: i64) {
         self.status = LaunchStatus::Graduated;
@@ -246,6 +466,30 @@ impl Launch {
     }
 }
 
+/// Per-launch moderation flags
+pub mod launch_flags {
+    /// Buys, sells and graduation are halted
+    pub const TRADING_PAUSED: u8 = 1 << 0;
+
+    /// Removed from the platform - buys, sells and graduation are halted
+    pub const DELISTED: u8 = 1 << 1;
+
+    /// Hidden from frontends (trading unaffected)
+    pub const HIDDEN: u8 = 1 << 2;
+
+    /// All defined flags
+    pub const ALL: u8 = TRADING_PAUSED | DELISTED | HIDDEN;
+}
+
+/// Fixed graduation payouts of launches created before they were configurable
+pub mod graduation {
+    /// SOL reward to token creator (2 SOL)
+    pub const CREATOR_REWARD_LAMPORTS: u64 = 2_000_000_000;
+
+    /// SOL fee to Launchr treasury (3 SOL)
+    pub const TREASURY_FEE_LAMPORTS: u64 = 3_000_000_000;
+}
+
 /// Token allocation constants
 pub mod allocation {
     /// Total supply: 1 billion tokens with 9 decimals
@@ -268,21 +512,6 @@ pub mod allocation {
     }
 }
 
-/// Graduation SOL distribution constants
-pub mod graduation {
-    /// SOL sent to Orbit DLMM LP (80 SOL)
-    pub const LP_SOL_LAMPORTS: u64 = 80_000_000_000;
-
-    /// SOL reward to token creator (2 SOL)
-    pub const CREATOR_REWARD_LAMPORTS: u64 = 2_000_000_000;
-
-    /// SOL fee to Launchr treasury (3 SOL)
-    pub const TREASURY_FEE_LAMPORTS: u64 = 3_000_000_000;
-
-    /// Total graduation threshold (must equal LP + Creator + Treasury)
-    pub const GRADUATION_THRESHOLD: u64 = 85_000_000_000;
-}
-
 /// Initial bonding curve parameters
 pub mod curve_params {
     /// Initial virtual SOL reserve (30 SOL)
@@ -328,7 +557,325 @@ impl Default for Launch {
             website: [0u8; 64],
             bump: 0,
             authority_bump: 0,
-            _reserved: [0u8; 32],
+            refund_window_secs: 0,
+            flags: 0,
+            graduation_creator_reward_bps: 0,
+            graduation_treasury_fee_bps: 0,
+            trading_starts_at: 0,
+            sniper_window_secs: 0,
+            sniper_max_buy_bps: 0,
+            sniper_max_wallet_bps: 0,
+            max_wallet_bps: 0,
+            graduation_payouts_set: false,
+            holders_tracked: false,
+            _reserved: [0u8; 3],
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::defaults;
+
+    fn refunding_launch(real_sol_reserve: u64, tokens_sold: u64) -> Launch {
+        Launch {
+            status: LaunchStatus::Refunding,
+            real_sol_reserve,
+            tokens_sold,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_refund_expiry() {
+        let mut launch = Launch {
+            created_at: 1_000,
+            ..Default::default()
+        };
+        assert_eq!(launch.refund_expiry(), None);
+        assert!(!launch.can_enable_refunds(i64::MAX));
+
+        launch.refund_window_secs = 500;
+        launch.graduation_threshold = 85_000_000_000;
+        assert!(!launch.can_enable_refunds(1_499));
+        assert!(launch.can_enable_refunds(1_500));
+
+        // A launch that reached its threshold must graduate instead
+        launch.real_sol_reserve = launch.graduation_threshold;
+        assert!(!launch.can_enable_refunds(1_500));
+    }
+
+    #[test]
+    fn test_refunds_never_over_drain() {
+        let mut launch = refunding_launch(1_000_000_007, 3_000_000_000);
+        let vault_before = launch.real_sol_reserve;
+
+        let mut paid = 0u64;
+        for tokens in [1_000_000_000u64, 999_999_999, 1_000_000_001] {
+            let sol = launch.refund_amount(tokens);
+            launch.record_refund(tokens, sol);
+            paid += sol;
+        }
+
+        assert!(paid <= vault_before);
+        assert_eq!(launch.tokens_sold, 0);
+        // Last redeemer sweeps the rounding dust
+        assert_eq!(paid, vault_before);
+        assert_eq!(launch.real_sol_reserve, 0);
+    }
+
+    #[test]
+    fn test_refunds_drain_vault_to_rent_minimum() {
+        const RENT_MINIMUM: u64 = 890_880;
+        let mut launch = refunding_launch(2_000_000_003, 7_000_000_000);
+        let mut vault = launch.real_sol_reserve + RENT_MINIMUM;
+
+        for tokens in [1_000_000_000u64, 2_500_000_000, 1, 3_499_999_999] {
+            let sol = launch.refund_payout(tokens, vault, RENT_MINIMUM);
+            launch.record_refund(tokens, sol);
+            vault -= sol;
+            // Every redemption leaves a rent-exempt vault, so the next transfer succeeds
+            assert!(vault >= RENT_MINIMUM);
+        }
+        assert_eq!(launch.tokens_sold, 0);
+        assert_eq!(vault, RENT_MINIMUM);
+
+        // A vault that holds less than the books say still never drops below rent
+        let launch = refunding_launch(1_000_000_000, 1_000);
+        let vault = 500_000_000 + RENT_MINIMUM;
+        assert_eq!(launch.refund_payout(1_000, vault, RENT_MINIMUM), 500_000_000);
+        assert_eq!(launch.refund_payout(1_000, RENT_MINIMUM - 1, RENT_MINIMUM), 0);
+    }
+
+    #[test]
+    fn test_refund_amount_caps_at_circulating() {
+        let launch = refunding_launch(10_000, 100);
+        assert_eq!(launch.refund_amount(50), 5_000);
+        assert_eq!(launch.refund_amount(1_000), 10_000);
+        assert_eq!(refunding_launch(10_000, 0).refund_amount(1), 0);
+    }
+
+    #[test]
+    fn test_moderation_flags() {
+        let mut launch = Launch::default();
+        assert!(!launch.is_paused() && !launch.is_delisted() && !launch.is_hidden());
+
+        launch.flags = launch_flags::TRADING_PAUSED | launch_flags::HIDDEN;
+        assert!(launch.is_paused());
+        assert!(!launch.is_delisted());
+        assert!(launch.is_hidden());
+
+        launch.flags = launch_flags::DELISTED;
+        assert!(!launch.is_paused());
+        assert!(launch.is_delisted());
+    }
+
+    #[test]
+    fn test_graduation_payouts_follow_threshold() {
+        let mut launch = Launch {
+            graduation_threshold: 85_000_000_000,
+            graduation_creator_reward_bps: 200,
+            graduation_treasury_fee_bps: 300,
+            graduation_payouts_set: true,
+            ..Default::default()
+        };
+        assert_eq!(launch.graduation_payouts(), (1_700_000_000, 2_550_000_000));
+
+        // Lower threshold scales the payouts down instead of blocking graduation
+        launch.graduation_threshold = 10_000_000_000;
+        let (creator_reward, treasury_fee) = launch.graduation_payouts();
+        assert_eq!((creator_reward, treasury_fee), (200_000_000, 300_000_000));
+        assert!(creator_reward + treasury_fee < launch.graduation_threshold);
+
+        launch.graduation_creator_reward_bps = 0;
+        launch.graduation_treasury_fee_bps = 0;
+        assert_eq!(launch.graduation_payouts(), (0, 0));
+    }
+
+    #[test]
+    fn test_pre_upgrade_launch_keeps_fixed_graduation_payouts() {
+        // Launches created before the shares existed read zeros in place of `_reserved`
+        let mut launch = Launch {
+            graduation_threshold: 85_000_000_000,
+            ..Default::default()
+        };
+        assert_eq!(launch.graduation_payouts(), (2_000_000_000, 3_000_000_000));
+
+        // Exactly as before the upgrade, not the default shares of the threshold
+        launch.graduation_creator_reward_bps = defaults::GRADUATION_CREATOR_REWARD_BPS;
+        launch.graduation_treasury_fee_bps = defaults::GRADUATION_TREASURY_FEE_BPS;
+        assert_eq!(launch.graduation_payouts(), (2_000_000_000, 3_000_000_000));
+
+        launch.graduation_payouts_set = true;
+        assert_eq!(launch.graduation_payouts(), (1_997_500_000, 3_000_500_000));
+    }
+
+    #[test]
+    fn test_fill_to_threshold() {
+        let mut launch = Launch {
+            status: LaunchStatus::Active,
+            graduation_threshold: 85_000_000_000,
+            real_sol_reserve: 84_000_000_000,
+            real_token_reserve: allocation::curve_tokens(),
+            ..Default::default()
+        };
+        assert_eq!(launch.sol_to_threshold(), 1_000_000_000);
+
+        launch.record_buy(1_000, launch.sol_to_threshold());
+        assert_eq!(launch.real_sol_reserve, launch.graduation_threshold);
+        assert_eq!(launch.sol_to_threshold(), 0);
+        assert_eq!(launch.status, LaunchStatus::PendingGraduation);
+    }
+
+    #[test]
+    fn test_trading_start() {
+        let mut launch = Launch {
+            created_at: 1_000,
+            trading_starts_at: 2_000,
+            refund_window_secs: 500,
+            graduation_threshold: 85_000_000_000,
+            ..Default::default()
+        };
+        assert!(!launch.is_tradeable(1_999));
+        assert!(launch.is_tradeable(2_000));
+
+        // The refund window only starts counting once trading opens
+        assert_eq!(launch.refund_expiry(), Some(2_500));
+        assert!(!launch.can_enable_refunds(1_500));
+
+        launch.status = LaunchStatus::PendingGraduation;
+        assert!(!launch.is_tradeable(2_000));
+    }
+
+    #[test]
+    fn test_sniper_caps_relax_linearly() {
+        let mut launch = Launch {
+            total_supply: allocation::TOTAL_SUPPLY,
+            graduation_threshold: 85_000_000_000,
+            trading_starts_at: 1_000,
+            sniper_window_secs: 100,
+            sniper_max_buy_bps: 100,
+            sniper_max_wallet_bps: 200,
+            ..Default::default()
+        };
+        assert_eq!(launch.sniper_max_buy_lamports(1_000), Some(850_000_000));
+        assert_eq!(launch.sniper_max_wallet_tokens(1_000), Some(allocation::TOTAL_SUPPLY / 50));
+
+        // Halfway through, each cap is halfway to 100%
+        assert_eq!(launch.sniper_cap_bps(100, 1_050), Some(5_050));
+        assert_eq!(launch.sniper_cap_bps(200, 1_050), Some(5_100));
+
+        // Unlimited once the window ends
+        assert_eq!(launch.sniper_max_buy_lamports(1_100), None);
+        assert_eq!(launch.sniper_max_wallet_tokens(1_100), None);
+
+        // A zero cap or window disables the check
+        launch.sniper_max_buy_bps = 0;
+        assert_eq!(launch.sniper_max_buy_lamports(1_000), None);
+        launch.sniper_window_secs = 0;
+        assert_eq!(launch.sniper_max_wallet_tokens(1_000), None);
+    }
+
+    #[test]
+    fn test_sniper_caps_hold_before_trading_starts() {
+        // A scheduled launch's initial buy happens before the start and gets the opening caps
+        let launch = Launch {
+            total_supply: allocation::TOTAL_SUPPLY,
+            graduation_threshold: 85_000_000_000,
+            created_at: 1_000,
+            trading_starts_at: 5_000,
+            sniper_window_secs: 100,
+            sniper_max_buy_bps: 100,
+            sniper_max_wallet_bps: 200,
+            ..Default::default()
+        };
+        assert_eq!(launch.sniper_max_buy_lamports(1_000), launch.sniper_max_buy_lamports(5_000));
+        assert_eq!(launch.sniper_max_wallet_tokens(1_000), Some(allocation::TOTAL_SUPPLY / 50));
+    }
+
+    #[test]
+    fn test_wallet_cap_lifts_at_graduation() {
+        let mut launch = Launch {
+            total_supply: allocation::TOTAL_SUPPLY,
+            ..Default::default()
+        };
+        assert_eq!(launch.max_wallet_tokens(), None);
+
+        launch.max_wallet_bps = 200;
+        assert_eq!(launch.max_wallet_tokens(), Some(allocation::TOTAL_SUPPLY / 50));
+
+        launch.graduate(Pubkey::new_unique(), 1_000);
+        assert_eq!(launch.max_wallet_tokens(), None);
+    }
+
+    fn new_position() -> UserPosition {
+        let mut position = UserPosition::default();
+        position.init(Pubkey::new_unique(), Pubkey::new_unique(), 255, 0);
+        position
+    }
+
+    #[test]
+    fn test_holder_count_follows_balance() {
+        let mut launch = Launch {
+            holders_tracked: true,
+            ..Default::default()
+        };
+        let mut alice = new_position();
+        let mut bob = new_position();
+
+        alice.record_buy(100, 1_000, 1);
+        launch.update_holder(&mut alice);
+        bob.record_buy(100, 1_000, 1);
+        launch.update_holder(&mut bob);
+        assert_eq!(launch.holder_count, 2);
+
+        // Buying more doesn't count twice
+        alice.record_buy(100, 1_000, 2);
+        launch.update_holder(&mut alice);
+        assert_eq!(launch.holder_count, 2);
+
+        // Full exit drops the holder, partial exit doesn't
+        alice.record_sell(200, 2_000, 3);
+        launch.update_holder(&mut alice);
+        bob.record_sell(50, 500, 3);
+        launch.update_holder(&mut bob);
+        assert_eq!(launch.holder_count, 1);
+
+        // Re-entering counts again
+        alice.record_buy(10, 100, 4);
+        launch.update_holder(&mut alice);
+        assert_eq!(launch.holder_count, 2);
+    }
+
+    #[test]
+    fn test_holder_count_migrates_pre_upgrade_accounts() {
+        // Old launches started at 1 for the creator and added 1 per position's first buy
+        let mut launch = Launch {
+            holder_count: 3,
+            ..Default::default()
+        };
+        let mut alice = UserPosition::default();
+        alice.record_buy(100, 1_000, 1);
+        let mut bob = UserPosition::default();
+        bob.record_buy(100, 1_000, 1);
+        bob.record_sell(100, 1_000, 2);
+
+        // Alice still holds: dropping the placeholder leaves her counted once
+        launch.update_holder(&mut alice);
+        assert!(launch.holders_tracked);
+        assert!(alice.is_holder);
+        assert_eq!(launch.holder_count, 2);
+
+        // Bob sold out under the old code and is uncounted
+        launch.update_holder(&mut bob);
+        assert!(!bob.is_holder);
+        assert_eq!(launch.holder_count, 1);
+
+        // Positions opened after the upgrade count from their first buy
+        let mut carol = new_position();
+        carol.record_buy(100, 1_000, 3);
+        launch.update_holder(&mut carol);
+        assert_eq!(launch.holder_count, 2);
+    }
+}
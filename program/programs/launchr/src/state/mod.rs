@@ -3,13 +3,21 @@
 //! All account state definitions for the Launchr protocol.
 
 pub mod config;
+pub mod config_change;
 pub mod launch;
 pub mod user_position;
+pub mod roles;
+pub mod multisig;
 
 pub use config::*;
+pub use config_change::*;
 pub use launch::*;
 pub use user_position::*;
+pub use roles::*;
+pub use multisig::*;
 
 // Re-export submodules for convenient access
 pub use launch::allocation;
+pub use launch::graduation;
+pub use launch::launch_flags;
 pub use launch::curve_params;
@@ -0,0 +1,288 @@
+//! Launchr - Native Multisig State
+//!
+//! M-of-N approval of admin actions without an external multisig program.
+//! When `Config.multisig_enabled` is set, admin instructions only run when
+//! passed a proposal for that exact action that has reached the threshold.
+
+use anchor_lang::prelude::*;
+use crate::instructions::{ConfigureMultisigParams, UpdateConfigParams};
+use crate::state::Role;
+
+/// Maximum number of multisig signers
+pub const MAX_MULTISIG_SIGNERS: usize = 10;
+
+/// Multisig signer set for admin actions
+#[account]
+#[derive(Default)]
+pub struct Multisig {
+    /// Config this multisig governs
+    pub config: Pubkey,
+
+    /// Signer keys (first `signer_count` entries are used)
+    pub signers: [Pubkey; MAX_MULTISIG_SIGNERS],
+
+    /// Number of active signers
+    pub signer_count: u8,
+
+    /// Approvals required to execute a proposal
+    pub threshold: u8,
+
+    /// Seconds a proposal stays open for approval and execution
+    pub proposal_lifetime_secs: u32,
+
+    /// Incremented whenever the signer set changes (invalidates open proposals)
+    pub signer_set_version: u32,
+
+    /// Number of proposals created (next proposal index)
+    pub proposal_count: u64,
+
+    /// Bump seed
+    pub bump: u8,
+
+    /// Reserved for future use
+    pub _reserved: [u8; 32],
+}
+
+impl Multisig {
+    /// Account space calculation
+    pub const LEN: usize = 8 +  // discriminator
+        32 +    // config
+        32 * MAX_MULTISIG_SIGNERS + // signers
+        1 +     // signer_count
+        1 +     // threshold
+        4 +     // proposal_lifetime_secs
+        4 +     // signer_set_version
+        8 +     // proposal_count
+        1 +     // bump
+        32;     // reserved
+
+    /// Active signer keys
+    pub fn active_signers(&self) -> &[Pubkey] {
+        &self.signers[..(self.signer_count as usize).min(MAX_MULTISIG_SIGNERS)]
+    }
+
+    /// Index of `key` in the signer set
+    pub fn signer_index(&self, key: &Pubkey) -> Option<usize> {
+        self.active_signers().iter().position(|signer| signer == key)
+    }
+
+    /// Check if `key` is a signer
+    pub fn is_signer(&self, key: &Pubkey) -> bool {
+        self.signer_index(key).is_some()
+    }
+
+    /// Replace the signer set
+    pub fn set_signers(&mut self, signers: &[Pubkey], threshold: u8, proposal_lifetime_secs: u32) {
+        self.signers = [Pubkey::default(); MAX_MULTISIG_SIGNERS];
+        self.signers[..signers.len()].copy_from_slice(signers);
+        self.signer_count = signers.len() as u8;
+        self.threshold = threshold;
+        self.proposal_lifetime_secs = proposal_lifetime_secs;
+        self.signer_set_version = self.signer_set_version.wrapping_add(1);
+    }
+}
+
+/// Admin action a proposal authorizes
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq)]
+pub enum AdminAction {
+    /// `update_config` with these params
+    UpdateConfig(UpdateConfigParams),
+    /// `queue_config_change` with these params
+    QueueConfigChange(UpdateConfigParams),
+    /// `cancel_config_change`
+    CancelConfigChange,
+    /// `propose_admin` for this key
+    ProposeAdmin { new_admin: Pubkey },
+    /// `cancel_admin_transfer`
+    CancelAdminTransfer,
+    /// `grant_role` to this holder
+    GrantRole { role: Role, holder: Pubkey },
+    /// `revoke_role`
+    RevokeRole { role: Role },
+    /// `withdraw_protocol_fees` to this destination
+    WithdrawProtocolFees { amount: Option<u64>, destination: Pubkey },
+    /// `configure_multisig` with these params
+    ConfigureMultisig(ConfigureMultisigParams),
+    /// `set_launch_flags` on this launch
+    SetLaunchFlags { launch: Pubkey, flags: u8 },
+}
+
+impl AdminAction {
+    /// Maximum serialized size (largest variant)
+    pub const MAX_SIZE: usize = 1 + ConfigureMultisigParams::MAX_SIZE;
+}
+
+// Config updates must fit in the space sized for the largest variant
+const _: () = assert!(UpdateConfigParams::MAX_SIZE <= ConfigureMultisigParams::MAX_SIZE);
+
+/// Proposal collecting multisig approvals for one admin action
+#[account]
+pub struct Proposal {
+    /// Multisig this proposal belongs to
+    pub multisig: Pubkey,
+
+    /// Signer that created the proposal (receives rent on close)
+    pub proposer: Pubkey,
+
+    /// Proposal index within the multisig
+    pub index: u64,
+
+    /// Action to authorize
+    pub action: AdminAction,
+
+    /// Bitmask of approving signer indexes
+    pub approvals: u16,
+
+    /// Signer set version the approvals refer to
+    pub signer_set_version: u32,
+
+    /// Unix timestamp of creation
+    pub created_at: i64,
+
+    /// Unix timestamp after which the proposal can no longer be approved or executed
+    pub expires_at: i64,
+
+    /// Whether the action has been executed
+    pub executed: bool,
+
+    /// Bump seed
+    pub bump: u8,
+}
+
+impl Proposal {
+    /// Account space calculation
+    pub const LEN: usize = 8 +  // discriminator
+        32 +    // multisig
+        32 +    // proposer
+        8 +     // index
+        AdminAction::MAX_SIZE + // action
+        2 +     // approvals
+        4 +     // signer_set_version
+        8 +     // created_at
+        8 +     // expires_at
+        1 +     // executed
+        1;      // bump
+
+    /// Number of approvals collected
+    pub fn approval_count(&self) -> u32 {
+        self.approvals.count_ones()
+    }
+
+    /// Record an approval from signer `index`
+    pub fn approve(&mut self, index: usize) {
+        self.approvals |= 1 << index;
+    }
+
+    /// Check if signer `index` has approved
+    pub fn has_approved(&self, index: usize) -> bool {
+        self.approvals & (1 << index) != 0
+    }
+
+    /// Check if the proposal has expired
+    pub fn is_expired(&self, now: i64) -> bool {
+        now >= self.expires_at
+    }
+
+    /// Check if the proposal is still open for approvals and execution
+    pub fn is_open(&self, multisig: &Multisig, now: i64) -> bool {
+        !self.executed
+            && !self.is_expired(now)
+            && self.signer_set_version == multisig.signer_set_version
+    }
+
+    /// Check if the proposal can execute
+    pub fn is_approved(&self, multisig: &Multisig, now: i64) -> bool {
+        self.is_open(multisig, now) && self.approval_count() >= multisig.threshold as u32
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn multisig(signers: &[Pubkey], threshold: u8) -> Multisig {
+        let mut multisig = Multisig::default();
+        multisig.set_signers(signers, threshold, 3_600);
+        multisig
+    }
+
+    fn proposal(multisig: &Multisig) -> Proposal {
+        Proposal {
+            multisig: Pubkey::new_unique(),
+            proposer: multisig.signers[0],
+            index: 0,
+            action: AdminAction::CancelAdminTransfer,
+            approvals: 0,
+            signer_set_version: multisig.signer_set_version,
+            created_at: 0,
+            expires_at: 3_600,
+            executed: false,
+            bump: 255,
+        }
+    }
+
+    #[test]
+    fn test_params_validation() {
+        let a = Pubkey::new_unique();
+        let b = Pubkey::new_unique();
+        let params = |signers: Vec<Pubkey>, threshold: u8| ConfigureMultisigParams {
+            signers,
+            threshold,
+            proposal_lifetime_secs: 3_600,
+            enabled: true,
+        };
+
+        assert!(params(vec![a, b], 2).is_valid());
+        assert!(!params(vec![a, b], 3).is_valid());
+        assert!(!params(vec![a, b], 0).is_valid());
+        assert!(!params(vec![a, a], 1).is_valid());
+        assert!(!params(vec![], 0).is_valid());
+        assert!(!params(vec![a, Pubkey::default()], 1).is_valid());
+        assert!(!params(vec![a; MAX_MULTISIG_SIGNERS + 1], 1).is_valid());
+    }
+
+    #[test]
+    fn test_threshold_and_expiry() {
+        let signers = [Pubkey::new_unique(), Pubkey::new_unique(), Pubkey::new_unique()];
+        let multisig = multisig(&signers, 2);
+        let mut proposal = proposal(&multisig);
+
+        proposal.approve(multisig.signer_index(&signers[0]).unwrap());
+        assert!(!proposal.is_approved(&multisig, 0));
+
+        // Approving twice doesn't count twice
+        proposal.approve(0);
+        assert_eq!(proposal.approval_count(), 1);
+
+        proposal.approve(multisig.signer_index(&signers[2]).unwrap());
+        assert!(proposal.is_approved(&multisig, 0));
+        assert!(!proposal.is_approved(&multisig, 3_600));
+    }
+
+    #[test]
+    fn test_signer_change_invalidates_proposals() {
+        let signers = [Pubkey::new_unique(), Pubkey::new_unique()];
+        let mut multisig = multisig(&signers, 1);
+        let mut proposal = proposal(&multisig);
+        proposal.approve(0);
+        assert!(proposal.is_approved(&multisig, 0));
+
+        multisig.set_signers(&signers[1..], 1, 3_600);
+        assert!(!proposal.is_approved(&multisig, 0));
+        assert!(!multisig.is_signer(&signers[0]));
+    }
+
+    #[test]
+    fn test_action_max_size() {
+        let action = AdminAction::ConfigureMultisig(ConfigureMultisigParams {
+            signers: vec![Pubkey::new_unique(); MAX_MULTISIG_SIGNERS],
+            threshold: 1,
+            proposal_lifetime_secs: 1,
+            enabled: true,
+        });
+        assert_eq!(action.try_to_vec().unwrap().len(), AdminAction::MAX_SIZE);
+
+        let update = AdminAction::UpdateConfig(UpdateConfigParams::default());
+        assert!(update.try_to_vec().unwrap().len() <= AdminAction::MAX_SIZE);
+    }
+}
@@ -0,0 +1,146 @@
+//! Launchr - Protocol Roles
+//! 
+//! Delegated permissions so day-to-day operations don't need the admin key.
+
+use anchor_lang::prelude::*;
+use crate::state::Config;
+
+/// Permission roles that can be delegated by the admin
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Role {
+    /// Can pause and unpause launches and trading
+    Pauser,
+    /// Can change fee rates (not the fee authority)
+    FeeManager,
+    /// Can change thresholds, windows and Orbit parameters
+    ParameterManager,
+    /// Can moderate individual launches
+    Moderator,
+}
+
+/// Role assignments for the protocol (one holder per role)
+#[account]
+#[derive(Default)]
+pub struct Roles {
+    /// Config these roles belong to
+    pub config: Pubkey,
+    
+    /// Pauser role holder (default = unassigned)
+    pub pauser: Pubkey,
+    
+    /// Fee manager role holder (default = unassigned)
+    pub fee_manager: Pubkey,
+    
+    /// Parameter manager role holder (default = unassigned)
+    pub parameter_manager: Pubkey,
+    
+    /// Moderator role holder (default = unassigned)
+    pub moderator: Pubkey,
+    
+    /// Bump seed
+    pub bump: u8,
+    
+    /// Reserved for future use
+    pub _reserved: [u8; 32],
+}
+
+impl Roles {
+    /// Account space calculation
+    pub const LEN: usize = 8 +  // discriminator
+        32 +    // config
+        32 +    // pauser
+        32 +    // fee_manager
+        32 +    // parameter_manager
+        32 +    // moderator
+        1 +     // bump
+        32;     // reserved
+    
+    /// Current holder of a role
+    pub fn holder(&self, role: Role) -> Pubkey {
+        match role {
+            Role::Pauser => self.pauser,
+            Role::FeeManager => self.fee_manager,
+            Role::ParameterManager => self.parameter_manager,
+            Role::Moderator => self.moderator,
+        }
+    }
+    
+    /// Assign a role (Pubkey::default() revokes it)
+    pub fn set_holder(&mut self, role: Role, holder: Pubkey) {
+        match role {
+            Role::Pauser => self.pauser = holder,
+            Role::FeeManager => self.fee_manager = holder,
+            Role::ParameterManager => self.parameter_manager = holder,
+            Role::Moderator => self.moderator = holder,
+        }
+    }
+    
+    /// Check if `key` may act with `role`
+    ///
+    /// The admin implicitly holds every role (unless multisig mode is on).
+    pub fn has_role(config: &Config, roles: Option<&Roles>, key: &Pubkey, role: Role) -> bool {
+        if config.is_admin(key) {
+            return true;
+        }
+        roles.is_some_and(|roles| {
+            let holder = roles.holder(role);
+            holder != Pubkey::default() && holder == *key
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_admin_holds_every_role() {
+        let config = Config {
+            admin: Pubkey::new_unique(),
+            ..Default::default()
+        };
+
+        for role in [Role::Pauser, Role::FeeManager, Role::ParameterManager, Role::Moderator] {
+            assert!(Roles::has_role(&config, None, &config.admin, role));
+        }
+    }
+
+    #[test]
+    fn test_role_holders() {
+        let config = Config {
+            admin: Pubkey::new_unique(),
+            ..Default::default()
+        };
+        let pauser = Pubkey::new_unique();
+        let mut roles = Roles::default();
+        roles.set_holder(Role::Pauser, pauser);
+
+        assert!(Roles::has_role(&config, Some(&roles), &pauser, Role::Pauser));
+        assert!(!Roles::has_role(&config, Some(&roles), &pauser, Role::FeeManager));
+        assert!(!Roles::has_role(&config, None, &pauser, Role::Pauser));
+
+        // Unassigned roles never match the default key
+        assert!(!Roles::has_role(&config, Some(&roles), &Pubkey::default(), Role::Moderator));
+
+        roles.set_holder(Role::Pauser, Pubkey::default());
+        assert!(!Roles::has_role(&config, Some(&roles), &pauser, Role::Pauser));
+    }
+
+    #[test]
+    fn test_multisig_mode_drops_admin_bypass() {
+        let mut config = Config {
+            admin: Pubkey::new_unique(),
+            multisig_enabled: true,
+            ..Default::default()
+        };
+        let pauser = Pubkey::new_unique();
+        let mut roles = Roles::default();
+        roles.set_holder(Role::Pauser, pauser);
+
+        assert!(!Roles::has_role(&config, Some(&roles), &config.admin, Role::Pauser));
+        assert!(Roles::has_role(&config, Some(&roles), &pauser, Role::Pauser));
+
+        config.multisig_enabled = false;
+        assert!(Roles::has_role(&config, Some(&roles), &config.admin, Role::Pauser));
+    }
+}
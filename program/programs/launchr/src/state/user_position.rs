@@ -62,8 +62,27 @@ pub struct UserPosition {
     /// Bump seed
     pub bump: u8,
     
+    // ========== Holder Tracking ==========
+    
+    /// Whether this position is counted in `Launch.holder_count`
+    pub is_holder: bool,
+    
+    // ========== Transfers ==========
+    
+    /// Total tokens received outside the curve (zero cost basis)
+    pub tokens_transferred_in: u64,
+    
+    /// Total tokens sent outside the curve
+    pub tokens_transferred_out: u64,
+    
+    // ========== Migration ==========
+    
+    /// Whether `is_holder` is maintained (false on positions opened before
+    /// holders were tracked by balance)
+    pub holder_tracked: bool,
+    
     /// Reserved for future use
-    pub _reserved: [u8; 32],
+    pub _reserved: [u8; 14],
 }
 
 impl UserPosition {
@@ -83,7 +102,11 @@ impl UserPosition {
         8 +     // avg_buy_price
         8 +     // cost_basis
         1 +     // bump
-        32;     // reserved
+        1 +     // is_holder
+        8 +     // tokens_transferred_in
+        8 +     // tokens_transferred_out
+        1 +     // holder_tracked
+        14;     // reserved
     
     /// Initialize a new position
     pub fn init(
@@ -96,6 +119,7 @@ impl UserPosition {
         self.launch = launch;
         self.user = user;
         self.bump = bump;
+        self.holder_tracked = true;
         self.first_trade_at = timestamp;
         self.last_trade_at = timestamp;
     }
@@ -120,9 +144,12 @@ impl UserPosition {
     }
     
     /// Record a sell transaction
+    ///
+    /// Tokens beyond the tracked balance arrived by transfer and carry no cost basis.
     pub fn record_sell(&mut self, tokens: u64, sol_amount: u64, timestamp: i64) {
         // Capture balance BEFORE sell for proportional cost basis reduction
         let balance_before = self.token_balance;
+        let tracked_tokens = tokens.min(balance_before);
 
         // Update totals
         self.tokens_sold = self.tokens_sold.saturating_add(tokens);
@@ -130,32 +157,78 @@ impl UserPosition {
         self.sol_received = self.sol_received.saturating_add(sol_amount);
 
         // Reduce cost basis proportionally to fraction of holdings sold
+        self.reduce_cost_basis(tracked_tokens, balance_before);
+        
+        // Update counts and timestamp
+        self.sell_count = self.sell_count.saturating_add(1);
+        self.last_trade_at = timestamp;
+    }
+    
+    /// Record tokens received outside the curve as a zero-cost lot
+    pub fn record_transfer_in(&mut self, tokens: u64) {
+        self.tokens_transferred_in = self.tokens_transferred_in.saturating_add(tokens);
+        self.token_balance = self.token_balance.saturating_add(tokens);
+        
+        // Same cost spread over more tokens
+        self.update_avg_buy_price();
+    }
+    
+    /// Record tokens sent outside the curve
+    ///
+    /// Reduces the cost basis proportionally, like a sell with no proceeds.
+    pub fn record_transfer_out(&mut self, tokens: u64) {
+        let balance_before = self.token_balance;
+        let tokens = tokens.min(balance_before);
+        
+        self.tokens_transferred_out = self.tokens_transferred_out.saturating_add(tokens);
+        self.token_balance = balance_before - tokens;
+        self.reduce_cost_basis(tokens, balance_before);
+    }
+    
+    /// Bring `token_balance` in line with the owner's actual token balance
+    ///
+    /// Returns the tokens recorded as transferred in and out.
+    pub fn sync_balance(&mut self, actual_balance: u64) -> (u64, u64) {
+        if actual_balance > self.token_balance {
+            let tokens_in = actual_balance - self.token_balance;
+            self.record_transfer_in(tokens_in);
+            (tokens_in, 0)
+        } else {
+            let tokens_out = self.token_balance - actual_balance;
+            self.record_transfer_out(tokens_out);
+            (0, tokens_out)
+        }
+    }
+    
+    /// Reduce cost basis by the share `tokens` made up of `balance_before`
+    fn reduce_cost_basis(&mut self, tokens: u64, balance_before: u64) {
         if balance_before > 0 {
             let sold_ratio = (tokens as u128 * 1_000_000_000) / balance_before as u128;
             let cost_reduction = ((self.cost_basis as u128 * sold_ratio) / 1_000_000_000) as u64;
             self.cost_basis = self.cost_basis.saturating_sub(cost_reduction);
         }
-        
-        // Recalculate average price
+        self.update_avg_buy_price();
+    }
+    
+    /// Recalculate the average price from cost basis and balance
+    fn update_avg_buy_price(&mut self) {
         if self.token_balance > 0 {
             self.avg_buy_price = ((self.cost_basis as u128 * 1_000_000_000) / self.token_balance as u128) as u64;
         } else {
             self.avg_buy_price = 0;
         }
-        
-        // Update counts and timestamp
-        self.sell_count = self.sell_count.saturating_add(1);
-        self.last_trade_at = timestamp;
     }
     
     /// Calculate realized PnL (profit/loss from completed sells)
     pub fn realized_pnl(&self) -> i64 {
         // realized_pnl = sol_received - (sol_spent * tokens_sold / tokens_bought)
-        if self.tokens_bought == 0 {
-            return 0;
-        }
-        
-        let cost_of_sold = ((self.sol_spent as u128 * self.tokens_sold as u128) / self.tokens_bought as u128) as u64;
+        // Tokens sold beyond those bought arrived by transfer at zero cost
+        let cost_of_sold = if self.tokens_bought == 0 {
+            0
+        } else {
+            let tokens_sold = self.tokens_sold.min(self.tokens_bought);
+            ((self.sol_spent as u128 * tokens_sold as u128) / self.tokens_bought as u128) as u64
+        };
         self.sol_received as i64 - cost_of_sold as i64
     }
     
@@ -191,6 +264,14 @@ impl UserPosition {
         self.buy_count == 0 && self.sell_count == 0
     }
     
+    /// Check if the position can be closed
+    ///
+    /// Once the launch has graduated the position is history only, so it can
+    /// be closed regardless of balance.
+    pub fn can_close(&self, launch_graduated: bool) -> bool {
+        launch_graduated || self.token_balance == 0
+    }
+    
     /// Get total trade count
     pub fn total_trades(&self) -> u32 {
         self.buy_count.saturating_add(self.sell_count)
@@ -230,4 +311,76 @@ mod tests {
         let pnl = pos.unrealized_pnl(new_price);
         assert!(pnl > 0);
     }
+    
+    #[test]
+    fn test_sell_without_cost_basis() {
+        // Tokens received by transfer, never bought
+        let mut pos = UserPosition::default();
+        pos.init(Pubkey::new_unique(), Pubkey::new_unique(), 255, 1000);
+        pos.record_sell(50_000_000_000, 500_000_000, 1001);
+        
+        assert_eq!(pos.token_balance, 0);
+        assert_eq!(pos.cost_basis, 0);
+        assert_eq!(pos.avg_buy_price, 0);
+        assert_eq!(pos.realized_pnl(), 500_000_000);
+        assert!(!pos.is_new());
+    }
+    
+    #[test]
+    fn test_sell_beyond_tracked_balance() {
+        let mut pos = UserPosition::default();
+        pos.init(Pubkey::new_unique(), Pubkey::new_unique(), 255, 1000);
+        
+        // Buy 100 tokens for 1 SOL, then sell those plus 100 transferred in
+        pos.record_buy(100_000_000_000, 1_000_000_000, 1001);
+        pos.record_sell(200_000_000_000, 2_200_000_000, 1002);
+        
+        assert_eq!(pos.token_balance, 0);
+        assert_eq!(pos.cost_basis, 0);
+        // Only the bought half carries cost
+        assert_eq!(pos.realized_pnl(), 1_200_000_000);
+    }
+    
+    #[test]
+    fn test_can_close() {
+        let mut pos = UserPosition::default();
+        pos.init(Pubkey::new_unique(), Pubkey::new_unique(), 255, 1000);
+        pos.record_buy(100_000_000_000, 1_000_000_000, 1001);
+        assert!(!pos.can_close(false));
+        assert!(pos.can_close(true));
+        
+        pos.record_sell(100_000_000_000, 1_100_000_000, 1002);
+        assert!(pos.can_close(false));
+        assert_eq!(pos.realized_pnl(), 100_000_000);
+    }
+    
+    #[test]
+    fn test_sync_balance() {
+        let mut pos = UserPosition::default();
+        pos.init(Pubkey::new_unique(), Pubkey::new_unique(), 255, 1000);
+        
+        // Buy 100 tokens for 1 SOL (avg 0.01 SOL/token)
+        pos.record_buy(100_000_000_000, 1_000_000_000, 1001);
+        assert_eq!(pos.avg_buy_price, 10_000_000);
+        
+        // 100 more arrive by transfer at zero cost, halving the average
+        assert_eq!(pos.sync_balance(200_000_000_000), (100_000_000_000, 0));
+        assert_eq!(pos.token_balance, 200_000_000_000);
+        assert_eq!(pos.cost_basis, 1_000_000_000);
+        assert_eq!(pos.avg_buy_price, 5_000_000);
+        
+        // Half leave by transfer, taking half the cost basis
+        assert_eq!(pos.sync_balance(100_000_000_000), (0, 100_000_000_000));
+        assert_eq!(pos.cost_basis, 500_000_000);
+        assert_eq!(pos.avg_buy_price, 5_000_000);
+        
+        // In sync already
+        assert_eq!(pos.sync_balance(100_000_000_000), (0, 0));
+        assert_eq!(pos.tokens_transferred_in, 100_000_000_000);
+        assert_eq!(pos.tokens_transferred_out, 100_000_000_000);
+        
+        // Transfers aren't trades
+        assert_eq!(pos.total_trades(), 1);
+        assert_eq!(pos.realized_pnl(), 0);
+    }
 }
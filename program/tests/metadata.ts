@@ -0,0 +1,189 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Program } from "@coral-xyz/anchor";
+import { NATIVE_MINT, TOKEN_PROGRAM_ID } from "@solana/spl-token";
+import { Keypair, PublicKey, SystemProgram, SYSVAR_RENT_PUBKEY } from "@solana/web3.js";
+import { expect } from "chai";
+import { Launchr } from "../target/types/launchr";
+
+// Loaded at genesis from tests/fixtures/mpl_token_metadata.so (see Anchor.toml)
+const TOKEN_METADATA_PROGRAM_ID = new PublicKey("metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s");
+const ORBIT_PROGRAM_ID = new PublicKey("Fn3fA3fjsmpULNL7E9U79jKTe1KHxPtQeWdURCbJXCnM");
+
+/** Fields of a Metaplex metadata account these tests check */
+interface MetadataAccount {
+  updateAuthority: PublicKey;
+  mint: PublicKey;
+  name: string;
+  symbol: string;
+  uri: string;
+  isMutable: boolean;
+}
+
+/** Decode a metadata account created with no creators, collection or uses */
+function decodeMetadata(data: Buffer): MetadataAccount {
+  let offset = 1; // key
+  const readPubkey = () => {
+    const key = new PublicKey(data.subarray(offset, offset + 32));
+    offset += 32;
+    return key;
+  };
+  // Strings are stored padded with NULs to their maximum length
+  const readString = () => {
+    const len = data.readUInt32LE(offset);
+    offset += 4;
+    const value = data.subarray(offset, offset + len).toString("utf8").replace(/\0+$/, "");
+    offset += len;
+    return value;
+  };
+
+  const updateAuthority = readPubkey();
+  const mint = readPubkey();
+  const name = readString();
+  const symbol = readString();
+  const uri = readString();
+  offset += 2; // seller_fee_basis_points
+  expect(data[offset], "creators").to.equal(0);
+  offset += 1;
+  offset += 1; // primary_sale_happened
+  const isMutable = data[offset] === 1;
+
+  return { updateAuthority, mint, name, symbol, uri, isMutable };
+}
+
+describe("token metadata", () => {
+  const provider = anchor.AnchorProvider.env();
+  anchor.setProvider(provider);
+
+  const program = anchor.workspace.Launchr as Program<Launchr>;
+  const admin = provider.wallet.publicKey;
+
+  const [config] = PublicKey.findProgramAddressSync([Buffer.from("launchr_config")], program.programId);
+
+  const emptyUpdate = {
+    newFeeAuthority: null,
+    newProtocolFeeBps: null,
+    newGraduationThreshold: null,
+    launchesPaused: null,
+    tradingPaused: null,
+    newRefundWindowSecs: null,
+    newDefaultBinStepBps: null,
+    newDefaultBaseFeeBps: null,
+    newOrbitProgramId: null,
+    newQuoteMint: null,
+    newConfigTimelockSecs: null,
+    newGraduationCreatorRewardBps: null,
+    newGraduationTreasuryFeeBps: null,
+    newMetadataPolicy: null,
+    newMaxTradingStartDelaySecs: null,
+    newSniperWindowSecs: null,
+    newSniperMaxBuyBps: null,
+    newSniperMaxWalletBps: null,
+  };
+
+  before(async () => {
+    await program.methods
+      .initConfig({
+        feeAuthority: admin,
+        protocolFeeBps: 100,
+        graduationThreshold: new anchor.BN(85_000_000_000),
+        orbitProgramId: ORBIT_PROGRAM_ID,
+        defaultBinStepBps: 25,
+        defaultBaseFeeBps: 30,
+      })
+      .accountsPartial({ admin, config, quoteMint: NATIVE_MINT })
+      .rpc();
+  });
+
+  /** Set the config's metadata policy and create a launch under it */
+  async function createLaunchUnder(metadataPolicy: object, symbol: string) {
+    await program.methods
+      .updateConfig({ ...emptyUpdate, newMetadataPolicy: metadataPolicy })
+      .accountsPartial({
+        authority: admin,
+        config,
+        roles: null,
+        newQuoteMint: null,
+        multisig: null,
+        proposal: null,
+      })
+      .rpc();
+
+    const mint = Keypair.generate();
+    const [launch] = PublicKey.findProgramAddressSync(
+      [Buffer.from("launch"), mint.publicKey.toBuffer()],
+      program.programId
+    );
+    const [launchAuthority] = PublicKey.findProgramAddressSync(
+      [Buffer.from("launch_authority"), launch.toBuffer()],
+      program.programId
+    );
+    const [metadata] = PublicKey.findProgramAddressSync(
+      [Buffer.from("metadata"), TOKEN_METADATA_PROGRAM_ID.toBuffer(), mint.publicKey.toBuffer()],
+      TOKEN_METADATA_PROGRAM_ID
+    );
+
+    await program.methods
+      .createLaunch({
+        name: `Policy ${symbol}`,
+        symbol,
+        uri: `https://example.com/${symbol}.json`,
+        twitter: null,
+        telegram: null,
+        website: null,
+        creatorFeeBps: 0,
+        initialBuyLamports: null,
+        minTokensOut: new anchor.BN(0),
+        tradingStartsAt: null,
+        sniperWindowSecs: null,
+        sniperMaxBuyBps: null,
+        sniperMaxWalletBps: null,
+        maxWalletBps: null,
+      })
+      .accountsPartial({
+        creator: admin,
+        config,
+        mint: mint.publicKey,
+        launch,
+        launchAuthority,
+        creatorPosition: null,
+        creatorTokenAccount: null,
+        metadata,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        tokenMetadataProgram: TOKEN_METADATA_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+        rent: SYSVAR_RENT_PUBKEY,
+      })
+      .signers([mint])
+      .rpc();
+
+    const account = await provider.connection.getAccountInfo(metadata);
+    expect(account, "metadata account").to.not.be.null;
+    expect(account!.owner.equals(TOKEN_METADATA_PROGRAM_ID)).to.be.true;
+
+    const decoded = decodeMetadata(account!.data);
+    expect(decoded.mint.equals(mint.publicKey)).to.be.true;
+    expect(decoded.name).to.equal(`Policy ${symbol}`);
+    expect(decoded.symbol).to.equal(symbol);
+    expect(decoded.uri).to.equal(`https://example.com/${symbol}.json`);
+
+    return { metadata: decoded, launchAuthority };
+  }
+
+  it("LockAtGraduation: launch authority holds update authority until graduation", async () => {
+    const { metadata, launchAuthority } = await createLaunchUnder({ lockAtGraduation: {} }, "LOCK");
+    expect(metadata.updateAuthority.equals(launchAuthority)).to.be.true;
+    expect(metadata.isMutable).to.be.true;
+  });
+
+  it("Creator: creator holds update authority", async () => {
+    const { metadata } = await createLaunchUnder({ creator: {} }, "CRTR");
+    expect(metadata.updateAuthority.equals(admin)).to.be.true;
+    expect(metadata.isMutable).to.be.true;
+  });
+
+  it("Immutable: metadata is immutable from creation", async () => {
+    const { metadata, launchAuthority } = await createLaunchUnder({ immutable: {} }, "IMMT");
+    expect(metadata.updateAuthority.equals(launchAuthority)).to.be.true;
+    expect(metadata.isMutable).to.be.false;
+  });
+});